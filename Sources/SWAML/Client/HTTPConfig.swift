@@ -0,0 +1,203 @@
+import Foundation
+#if canImport(FoundationNetworking)
+import FoundationNetworking
+#endif
+
+/// HTTP transport settings for an LLM client
+public struct HTTPConfig: Sendable {
+    /// Maximum time to wait for response data before the request fails (in seconds).
+    /// Applied as the URLRequest idle timeout, so it resets whenever bytes arrive.
+    public let readTimeout: TimeInterval?
+
+    /// Maximum wall-clock time for a single request attempt (in seconds)
+    public let totalTimeout: TimeInterval?
+
+    /// Proxy to route this client's requests through
+    public let proxy: ProxyConfig?
+
+    /// Extra trusted roots and certificate pins
+    public let tls: TLSConfig?
+
+    /// Connection reuse settings
+    public let connectionPool: ConnectionPoolConfig?
+
+    /// Log method, URL, redacted headers, sizes, status and timing for every attempt.
+    /// Logging is also enabled for all clients by setting `SWAML_LOG_HTTP=1`.
+    public let logRequests: Bool
+
+    /// Where log entries go (defaults to standard error)
+    public let logHandler: HTTPLogHandler?
+
+    public init(
+        readTimeout: TimeInterval? = nil,
+        totalTimeout: TimeInterval? = nil,
+        proxy: ProxyConfig? = nil,
+        tls: TLSConfig? = nil,
+        connectionPool: ConnectionPoolConfig? = nil,
+        logRequests: Bool = false,
+        logHandler: HTTPLogHandler? = nil
+    ) {
+        self.readTimeout = readTimeout
+        self.totalTimeout = totalTimeout
+        self.proxy = proxy
+        self.tls = tls
+        self.connectionPool = connectionPool
+        self.logRequests = logRequests
+        self.logHandler = logHandler
+    }
+
+    /// Default configuration (URLSession defaults, no total deadline)
+    public static let `default` = HTTPConfig()
+}
+
+// MARK: - Proxy
+
+/// HTTP/HTTPS proxy settings for a single client
+public struct ProxyConfig: Sendable, Equatable {
+    /// Proxy URL (e.g., "http://proxy.corp.example:3128")
+    public let url: URL
+
+    /// Hosts that bypass the proxy.
+    /// "example.com" matches the host and its subdomains, ".example.com" only subdomains,
+    /// and "*" disables the proxy entirely.
+    public let noProxy: [String]
+
+    public init(url: URL, noProxy: [String] = []) {
+        self.url = url
+        self.noProxy = noProxy
+    }
+
+    /// Create from a proxy URL string, returning nil if it cannot be parsed
+    public init?(_ urlString: String, noProxy: [String] = []) {
+        guard let url = URL(string: urlString), url.host != nil else {
+            return nil
+        }
+        self.init(url: url, noProxy: noProxy)
+    }
+
+    /// Whether requests to the given host should go through the proxy
+    public func applies(to host: String) -> Bool {
+        let host = host.lowercased()
+        for entry in noProxy {
+            let pattern = entry.trimmingCharacters(in: .whitespaces).lowercased()
+            if pattern.isEmpty {
+                continue
+            }
+            if pattern == "*" {
+                return false
+            }
+            if pattern.hasPrefix(".") {
+                if host.hasSuffix(pattern) {
+                    return false
+                }
+            } else if host == pattern || host.hasSuffix(".\(pattern)") {
+                return false
+            }
+        }
+        return true
+    }
+
+    /// Proxy dictionary for `URLSessionConfiguration.connectionProxyDictionary`
+    public var connectionProxyDictionary: [AnyHashable: Any] {
+        let host = url.host ?? ""
+        let port = url.port ?? (url.scheme?.lowercased() == "https" ? 443 : 80)
+        return [
+            "HTTPEnable": 1,
+            "HTTPProxy": host,
+            "HTTPPort": port,
+            "HTTPSEnable": 1,
+            "HTTPSProxy": host,
+            "HTTPSPort": port
+        ]
+    }
+}
+
+// MARK: - Connection Pool
+
+/// Connection reuse settings for a client's URLSession.
+///
+/// URLSession keeps idle connections alive and negotiates HTTP/2 on its own; these
+/// settings cover the knobs it exposes. Leave a value nil to keep the platform default.
+public struct ConnectionPoolConfig: Sendable, Equatable {
+    /// Maximum simultaneous connections to a single host
+    public let maxConnectionsPerHost: Int?
+
+    /// Whether to pipeline HTTP/1.1 requests on a connection
+    public let usesPipelining: Bool?
+
+    /// Whether to wait for connectivity instead of failing immediately when offline
+    public let waitsForConnectivity: Bool?
+
+    public init(
+        maxConnectionsPerHost: Int? = nil,
+        usesPipelining: Bool? = nil,
+        waitsForConnectivity: Bool? = nil
+    ) {
+        self.maxConnectionsPerHost = maxConnectionsPerHost
+        self.usesPipelining = usesPipelining
+        self.waitsForConnectivity = waitsForConnectivity
+    }
+
+    /// Suited to server deployments that issue many concurrent calls to one provider
+    public static let server = ConnectionPoolConfig(maxConnectionsPerHost: 16, waitsForConnectivity: false)
+
+    /// Apply these settings to a session configuration
+    func apply(to configuration: URLSessionConfiguration) {
+        if let maxConnectionsPerHost = maxConnectionsPerHost {
+            configuration.httpMaximumConnectionsPerHost = maxConnectionsPerHost
+        }
+        if let usesPipelining = usesPipelining {
+            configuration.httpShouldUsePipelining = usesPipelining
+        }
+        if let waitsForConnectivity = waitsForConnectivity {
+            configuration.waitsForConnectivity = waitsForConnectivity
+        }
+    }
+}
+
+// MARK: - Session Construction
+
+extension HTTPConfig {
+    /// Names of the set options that can only be applied by building a dedicated session
+    var sessionSettings: [String] {
+        var names: [String] = []
+        if proxy != nil {
+            names.append("proxy")
+        }
+        if tls != nil {
+            names.append("tls")
+        }
+        if connectionPool != nil {
+            names.append("connectionPool")
+        }
+        return names
+    }
+
+    /// Build a URLSession for requests to `host`.
+    ///
+    /// Returns `URLSession.shared` when no setting needs a dedicated session.
+    func makeSession(forHost host: String) -> URLSession {
+        let activeProxy = proxy.flatMap { $0.applies(to: host) ? $0 : nil }
+        guard activeProxy != nil || tls != nil || connectionPool != nil else {
+            return URLSession.shared
+        }
+
+        let configuration = URLSessionConfiguration.default
+        if let proxy = activeProxy {
+            configuration.connectionProxyDictionary = proxy.connectionProxyDictionary
+        }
+        connectionPool?.apply(to: configuration)
+
+        #if canImport(Security) && canImport(CryptoKit)
+        if let tls = tls {
+            return URLSession(
+                configuration: configuration,
+                delegate: TLSTrustDelegate(tls: tls),
+                delegateQueue: nil
+            )
+        }
+        #endif
+
+        return URLSession(configuration: configuration)
+    }
+}
@@ -0,0 +1,141 @@
+import Foundation
+#if canImport(FoundationNetworking)
+import FoundationNetworking
+#endif
+
+/// A single logged HTTP attempt
+public struct HTTPLogEntry: Sendable {
+    public let method: String
+    /// Request URL, without user info or query string
+    public let url: URL?
+    /// Request headers, with credentials replaced by "[REDACTED]"
+    public let requestHeaders: [String: String]
+    public let requestBodySize: Int
+    public let statusCode: Int?
+    public let responseBodySize: Int?
+    public let duration: TimeInterval
+    /// Error description if the attempt failed before a response arrived
+    public let error: String?
+
+    public init(
+        method: String,
+        url: URL?,
+        requestHeaders: [String: String],
+        requestBodySize: Int,
+        statusCode: Int?,
+        responseBodySize: Int?,
+        duration: TimeInterval,
+        error: String?,
+        sensitiveHeaders: Set<String> = []
+    ) {
+        self.method = method
+        self.url = url.map(HTTPLogging.redact)
+        self.requestHeaders = HTTPLogging.redact(requestHeaders, also: sensitiveHeaders)
+        self.requestBodySize = requestBodySize
+        self.statusCode = statusCode
+        self.responseBodySize = responseBodySize
+        self.duration = duration
+        self.error = error
+    }
+
+    /// Single-line summary
+    public var description: String {
+        var parts = ["[swaml] \(method) \(url?.absoluteString ?? "<no url>")"]
+        if let statusCode = statusCode {
+            parts.append("status=\(statusCode)")
+        }
+        if let error = error {
+            parts.append("error=\"\(error)\"")
+        }
+        parts.append("req=\(requestBodySize)B")
+        if let responseBodySize = responseBodySize {
+            parts.append("resp=\(responseBodySize)B")
+        }
+        parts.append(String(format: "time=%.3fs", duration))
+
+        let headers = requestHeaders
+            .sorted { $0.key.lowercased() < $1.key.lowercased() }
+            .map { "\($0.key): \($0.value)" }
+            .joined(separator: ", ")
+        parts.append("headers={\(headers)}")
+
+        return parts.joined(separator: " ")
+    }
+}
+
+/// Receives one entry per HTTP attempt
+public typealias HTTPLogHandler = @Sendable (HTTPLogEntry) -> Void
+
+/// Helpers for wire-level request logging
+public enum HTTPLogging {
+    /// Set to "1" or "true" to log every client's requests to stderr
+    public static let environmentVariable = "SWAML_LOG_HTTP"
+
+    /// Headers whose values are never logged
+    public static let sensitiveHeaders: Set<String> = [
+        "authorization",
+        "proxy-authorization",
+        "x-api-key",
+        "api-key",
+        "cookie",
+        "set-cookie"
+    ]
+
+    /// Header name fragments that mark a value as a credential (e.g. "x-goog-api-key",
+    /// "x-auth-token", "client-secret")
+    static let sensitiveNameFragments = ["key", "token", "secret", "auth", "password", "cookie"]
+
+    /// Whether logging is turned on through the environment
+    public static var isEnabledByEnvironment: Bool {
+        guard let value = ProcessInfo.processInfo.environment[environmentVariable] else {
+            return false
+        }
+        return ["1", "true", "yes"].contains(value.lowercased())
+    }
+
+    /// Default handler that writes each entry to standard error
+    public static let standardError: HTTPLogHandler = { entry in
+        FileHandle.standardError.write(Data((entry.description + "\n").utf8))
+    }
+
+    /// Whether a header's value should be kept out of logs
+    public static func isSensitive(header name: String) -> Bool {
+        let name = name.lowercased()
+        return sensitiveHeaders.contains(name) || sensitiveNameFragments.contains { name.contains($0) }
+    }
+
+    /// Replace credential header values with a placeholder
+    ///
+    /// - Parameter also: Extra header names to redact, such as a provider's auth header
+    public static func redact(_ headers: [String: String], also extra: Set<String> = []) -> [String: String] {
+        let extra = Set(extra.map { $0.lowercased() })
+        var redacted: [String: String] = [:]
+        for (key, value) in headers {
+            let sensitive = isSensitive(header: key) || extra.contains(key.lowercased())
+            redacted[key] = sensitive ? "[REDACTED]" : value
+        }
+        return redacted
+    }
+
+    /// Drop user info and the query string, which can carry credentials (e.g. `?api-key=`)
+    public static func redact(_ url: URL) -> URL {
+        guard var components = URLComponents(url: url, resolvingAgainstBaseURL: false) else {
+            return url
+        }
+        components.user = nil
+        components.password = nil
+        components.query = nil
+        components.fragment = nil
+        return components.url ?? url
+    }
+}
+
+extension HTTPConfig {
+    /// The handler to log through, or nil if logging is off for this client
+    var activeLogHandler: HTTPLogHandler? {
+        guard logRequests || HTTPLogging.isEnabledByEnvironment else {
+            return nil
+        }
+        return logHandler ?? HTTPLogging.standardError
+    }
+}
@@ -0,0 +1,167 @@
+import Foundation
+#if canImport(ImageIO)
+import ImageIO
+#endif
+
+/// Limits and conversions applied when loading an image into a message
+public struct ImageInputOptions: Sendable {
+    /// Largest encoded image accepted (after any downscaling), in bytes
+    public var maxBytes: Int
+
+    /// Downscale so the longest side is at most this many pixels.
+    /// Requires ImageIO; on other platforms setting it fails with `SwamlError.invalidMedia`.
+    public var maxDimension: Int?
+
+    public init(maxBytes: Int = 20 * 1024 * 1024, maxDimension: Int? = nil) {
+        self.maxBytes = maxBytes
+        self.maxDimension = maxDimension
+    }
+
+    /// 20 MB, no downscaling
+    public static let `default` = ImageInputOptions()
+
+    /// Image types accepted by the OpenAI and Anthropic APIs
+    public static let supportedMediaTypes: Set<String> = [
+        "image/png",
+        "image/jpeg",
+        "image/gif",
+        "image/webp"
+    ]
+}
+
+// MARK: - Loading Images
+
+extension ChatMessage.ContentPart {
+    /// Load an image from a local file, or reference a remote one
+    ///
+    /// Local files are read, validated and inlined as base64. `http(s)` URLs are passed
+    /// through unchanged for the provider to fetch.
+    ///
+    /// - Throws: SwamlError.invalidMedia if the file cannot be read or fails validation
+    public static func image(contentsOf url: URL, options: ImageInputOptions = .default) throws -> ChatMessage.ContentPart {
+        guard url.isFileURL else {
+            return .imageURL(url)
+        }
+
+        let data: Data
+        do {
+            data = try Data(contentsOf: url)
+        } catch {
+            throw SwamlError.invalidMedia("Cannot read image at \(url.path): \(error.localizedDescription)")
+        }
+        return try image(data: data, options: options)
+    }
+
+    /// Inline raw image bytes
+    ///
+    /// - Parameters:
+    ///   - data: Encoded image bytes
+    ///   - mediaType: MIME type; detected from the bytes when nil
+    /// - Throws: SwamlError.invalidMedia if the type is unsupported or the image is too large
+    public static func image(
+        data: Data,
+        mediaType: String? = nil,
+        options: ImageInputOptions = .default
+    ) throws -> ChatMessage.ContentPart {
+        guard let detected = mediaType?.lowercased() ?? detectImageMediaType(data) else {
+            throw SwamlError.invalidMedia("Unrecognized image format")
+        }
+        guard ImageInputOptions.supportedMediaTypes.contains(detected) else {
+            throw SwamlError.invalidMedia("Unsupported image type \(detected)")
+        }
+
+        var bytes = data
+        var type = detected
+        if let maxDimension = options.maxDimension {
+            (bytes, type) = try downscale(data, mediaType: detected, maxDimension: maxDimension)
+        }
+
+        guard bytes.count <= options.maxBytes else {
+            throw SwamlError.invalidMedia("Image is \(bytes.count) bytes, over the \(options.maxBytes)-byte limit")
+        }
+
+        return .imageBase64(data: bytes.base64EncodedString(), mediaType: type)
+    }
+
+    /// Inline an image from a `data:` URI (e.g. "data:image/png;base64,iVBOR...")
+    ///
+    /// - Throws: SwamlError.invalidMedia if the URI is malformed or fails validation
+    public static func image(dataURI: String, options: ImageInputOptions = .default) throws -> ChatMessage.ContentPart {
+        guard dataURI.hasPrefix("data:"), let comma = dataURI.firstIndex(of: ",") else {
+            throw SwamlError.invalidMedia("Not a data URI")
+        }
+
+        let header = dataURI[dataURI.index(dataURI.startIndex, offsetBy: 5)..<comma]
+        let parameters = header.split(separator: ";").map(String.init)
+        guard parameters.contains("base64") else {
+            throw SwamlError.invalidMedia("Only base64 data URIs are supported")
+        }
+        guard let data = Data(base64Encoded: String(dataURI[dataURI.index(after: comma)...])) else {
+            throw SwamlError.invalidMedia("Data URI payload is not valid base64")
+        }
+
+        let mediaType = parameters.first.flatMap { $0.contains("/") ? $0 : nil }
+        return try image(data: data, mediaType: mediaType, options: options)
+    }
+
+    /// Detect an image MIME type from its leading bytes
+    static func detectImageMediaType(_ data: Data) -> String? {
+        let bytes = [UInt8](data.prefix(12))
+        if bytes.starts(with: [0x89, 0x50, 0x4E, 0x47]) {
+            return "image/png"
+        }
+        if bytes.starts(with: [0xFF, 0xD8, 0xFF]) {
+            return "image/jpeg"
+        }
+        if bytes.starts(with: Array("GIF8".utf8)) {
+            return "image/gif"
+        }
+        if bytes.count >= 12, bytes.starts(with: Array("RIFF".utf8)), Array(bytes[8..<12]) == Array("WEBP".utf8) {
+            return "image/webp"
+        }
+        return nil
+    }
+
+    // MARK: - Downscaling
+
+    #if canImport(ImageIO)
+    private static func downscale(_ data: Data, mediaType: String, maxDimension: Int) throws -> (Data, String) {
+        guard let source = CGImageSourceCreateWithData(data as CFData, nil),
+              let properties = CGImageSourceCopyPropertiesAtIndex(source, 0, nil) as? [CFString: Any],
+              let width = properties[kCGImagePropertyPixelWidth] as? Int,
+              let height = properties[kCGImagePropertyPixelHeight] as? Int else {
+            throw SwamlError.invalidMedia("Cannot decode image")
+        }
+
+        if max(width, height) <= maxDimension {
+            return (data, mediaType)
+        }
+
+        let thumbnailOptions: [CFString: Any] = [
+            kCGImageSourceCreateThumbnailFromImageAlways: true,
+            kCGImageSourceCreateThumbnailWithTransform: true,
+            kCGImageSourceThumbnailMaxPixelSize: maxDimension
+        ]
+        guard let image = CGImageSourceCreateThumbnailAtIndex(source, 0, thumbnailOptions as CFDictionary) else {
+            throw SwamlError.invalidMedia("Cannot downscale image")
+        }
+
+        // Keep PNG for images that may have transparency; re-encode everything else as JPEG
+        let (outputType, uti) = mediaType == "image/png" ? ("image/png", "public.png") : ("image/jpeg", "public.jpeg")
+        let output = NSMutableData()
+        guard let destination = CGImageDestinationCreateWithData(output as CFMutableData, uti as CFString, 1, nil) else {
+            throw SwamlError.invalidMedia("Cannot encode downscaled image")
+        }
+        CGImageDestinationAddImage(destination, image, [kCGImageDestinationLossyCompressionQuality: 0.85] as CFDictionary)
+        guard CGImageDestinationFinalize(destination) else {
+            throw SwamlError.invalidMedia("Cannot encode downscaled image")
+        }
+
+        return (output as Data, outputType)
+    }
+    #else
+    private static func downscale(_ data: Data, mediaType: String, maxDimension: Int) throws -> (Data, String) {
+        throw SwamlError.invalidMedia("Image downscaling requires ImageIO, which is not available on this platform")
+    }
+    #endif
+}
@@ -6,14 +6,45 @@ import FoundationNetworking
 /// HTTP client for making requests to LLM APIs
 public actor LLMClient {
     public let provider: LLMProvider
+    public let httpConfig: HTTPConfig
     private let session: URLSession
 
-    public init(provider: LLMProvider, session: URLSession? = nil) {
+    /// Whether `session` was built from `httpConfig`, and is invalidated with the client
+    private let ownsSession: Bool
+
+    /// Settings a caller-supplied session cannot apply; requests fail rather than skip them
+    private let unappliedSettings: [String]
+
+    /// Create a client.
+    ///
+    /// When `session` is given, `httpConfig`'s proxy, TLS and connection pool settings cannot be
+    /// applied to it, and requests fail with a configuration error if any of them are set.
+    public init(provider: LLMProvider, session: URLSession? = nil, httpConfig: HTTPConfig = .default) {
         self.provider = provider
-        self.session = session ?? URLSession.shared
+        self.httpConfig = httpConfig
+        if let session = session {
+            self.session = session
+            self.ownsSession = false
+            self.unappliedSettings = httpConfig.sessionSettings
+        } else {
+            let session = httpConfig.makeSession(forHost: provider.baseURL.host ?? "")
+            self.session = session
+            self.ownsSession = session !== URLSession.shared
+            self.unappliedSettings = []
+        }
+    }
+
+    deinit {
+        // A dedicated session retains its delegate until invalidated
+        if ownsSession {
+            session.finishTasksAndInvalidate()
+        }
     }
 
     /// Send a chat completion request to the LLM
+    ///
+    /// `seed` and the frequency and presence penalties are only sent to OpenAI-compatible
+    /// providers; Anthropic ignores them.
     public func complete(
         model: String,
         messages: [ChatMessage],
@@ -21,7 +52,11 @@ public actor LLMClient {
         temperature: Double? = nil,
         maxTokens: Int? = nil,
         topP: Double? = nil,
-        stop: [String]? = nil
+        frequencyPenalty: Double? = nil,
+        presencePenalty: Double? = nil,
+        stop: [String]? = nil,
+        seed: Int? = nil,
+        timeout: TimeInterval? = nil
     ) async throws -> LLMResponse {
         if provider.isOpenAICompatible {
             return try await completeOpenAI(
@@ -31,7 +66,11 @@ public actor LLMClient {
                 temperature: temperature,
                 maxTokens: maxTokens,
                 topP: topP,
-                stop: stop
+                frequencyPenalty: frequencyPenalty,
+                presencePenalty: presencePenalty,
+                stop: stop,
+                seed: seed,
+                timeout: timeout
             )
         } else {
             return try await completeAnthropic(
@@ -40,11 +79,121 @@ public actor LLMClient {
                 temperature: temperature,
                 maxTokens: maxTokens ?? 4096,
                 topP: topP,
-                stop: stop
+                stop: stop,
+                timeout: timeout
             )
         }
     }
 
+    // MARK: - Transport
+
+    /// Send a request, applying the read timeout and enforcing the total deadline.
+    /// Each attempt is logged when request logging is enabled.
+    ///
+    /// A per-call `timeout` takes precedence over `httpConfig.totalTimeout`.
+    private func send(_ request: URLRequest, timeout: TimeInterval?) async throws -> (Data, URLResponse) {
+        #if !(canImport(Security) && canImport(CryptoKit))
+        if httpConfig.tls != nil {
+            throw SwamlError.configurationError("Custom TLS trust requires the Security framework, which is unavailable on this platform")
+        }
+        #endif
+
+        if !unappliedSettings.isEmpty {
+            throw SwamlError.configurationError(
+                "HTTPConfig \(unappliedSettings.joined(separator: ", ")) cannot be applied to a custom URLSession; configure the session instead"
+            )
+        }
+
+        var request = request
+        if let readTimeout = httpConfig.readTimeout {
+            guard readTimeout.isFinite, readTimeout > 0 else {
+                throw SwamlError.configurationError("Read timeout must be a positive number of seconds, got \(readTimeout)")
+            }
+            request.timeoutInterval = readTimeout
+        }
+
+        guard let logHandler = httpConfig.activeLogHandler else {
+            return try await perform(request, timeout: timeout)
+        }
+
+        let start = Date()
+        do {
+            let (data, response) = try await perform(request, timeout: timeout)
+            logHandler(makeLogEntry(
+                for: request,
+                statusCode: (response as? HTTPURLResponse)?.statusCode,
+                responseBodySize: data.count,
+                start: start,
+                error: nil
+            ))
+            return (data, response)
+        } catch {
+            logHandler(makeLogEntry(
+                for: request,
+                statusCode: nil,
+                responseBodySize: nil,
+                start: start,
+                error: error.localizedDescription
+            ))
+            throw error
+        }
+    }
+
+    /// Longest deadline honoured, in seconds (about 300 years)
+    private static let maxDeadline: TimeInterval = 1e10
+
+    /// Perform a request, enforcing the total deadline if one applies
+    ///
+    /// - Throws: SwamlError.configurationError if the deadline is not a positive, finite number
+    private func perform(_ request: URLRequest, timeout: TimeInterval?) async throws -> (Data, URLResponse) {
+        guard let deadline = timeout ?? httpConfig.totalTimeout else {
+            return try await session.data(for: request)
+        }
+        guard deadline.isFinite, deadline > 0 else {
+            throw SwamlError.configurationError("Request timeout must be a positive number of seconds, got \(deadline)")
+        }
+        // Clamp so the nanosecond conversion cannot overflow
+        let nanoseconds = UInt64(min(deadline, Self.maxDeadline) * 1_000_000_000)
+
+        let session = self.session
+        let finalRequest = request
+        return try await withThrowingTaskGroup(of: (Data, URLResponse).self) { group in
+            group.addTask {
+                try await session.data(for: finalRequest)
+            }
+            group.addTask {
+                try await Task.sleep(nanoseconds: nanoseconds)
+                throw SwamlError.requestTimeout(seconds: deadline)
+            }
+            defer { group.cancelAll() }
+
+            guard let result = try await group.next() else {
+                throw SwamlError.internalError("Request finished without a result")
+            }
+            return result
+        }
+    }
+
+    private func makeLogEntry(
+        for request: URLRequest,
+        statusCode: Int?,
+        responseBodySize: Int?,
+        start: Date,
+        error: String?
+    ) -> HTTPLogEntry {
+        HTTPLogEntry(
+            method: request.httpMethod ?? "GET",
+            url: request.url,
+            requestHeaders: request.allHTTPHeaderFields ?? [:],
+            requestBodySize: request.httpBody?.count ?? 0,
+            statusCode: statusCode,
+            responseBodySize: responseBodySize,
+            duration: Date().timeIntervalSince(start),
+            error: error,
+            sensitiveHeaders: [provider.authHeader.name]
+        )
+    }
+
     // MARK: - OpenAI-Compatible API
 
     private func completeOpenAI(
@@ -54,7 +203,11 @@ public actor LLMClient {
         temperature: Double?,
         maxTokens: Int?,
         topP: Double?,
-        stop: [String]?
+        frequencyPenalty: Double?,
+        presencePenalty: Double?,
+        stop: [String]?,
+        seed: Int?,
+        timeout: TimeInterval?
     ) async throws -> LLMResponse {
         let url = provider.baseURL.appendingPathComponent("chat/completions")
         var request = URLRequest(url: url)
@@ -85,13 +238,22 @@ public actor LLMClient {
         if let topP = topP {
             body["top_p"] = topP
         }
+        if let frequencyPenalty = frequencyPenalty {
+            body["frequency_penalty"] = frequencyPenalty
+        }
+        if let presencePenalty = presencePenalty {
+            body["presence_penalty"] = presencePenalty
+        }
         if let stop = stop, !stop.isEmpty {
             body["stop"] = stop
         }
+        if let seed = seed {
+            body["seed"] = seed
+        }
 
         request.httpBody = try JSONSerialization.data(withJSONObject: body)
 
-        let (data, response) = try await session.data(for: request)
+        let (data, response) = try await send(request, timeout: timeout)
 
         guard let httpResponse = response as? HTTPURLResponse else {
             throw SwamlError.networkError("Invalid response type")
@@ -151,7 +313,8 @@ public actor LLMClient {
         temperature: Double?,
         maxTokens: Int,
         topP: Double?,
-        stop: [String]?
+        stop: [String]?,
+        timeout: TimeInterval?
     ) async throws -> LLMResponse {
         let url = provider.baseURL.appendingPathComponent("messages")
         var request = URLRequest(url: url)
@@ -197,7 +360,7 @@ public actor LLMClient {
 
         request.httpBody = try JSONSerialization.data(withJSONObject: body)
 
-        let (data, response) = try await session.data(for: request)
+        let (data, response) = try await send(request, timeout: timeout)
 
         guard let httpResponse = response as? HTTPURLResponse else {
             throw SwamlError.networkError("Invalid response type")
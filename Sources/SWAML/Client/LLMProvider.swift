@@ -7,6 +7,20 @@ public enum LLMProvider: Sendable {
     case anthropic(apiKey: String)
     case custom(baseURL: URL, apiKey: String, headers: [String: String] = [:])
 
+    /// Short provider name used in "provider/model" client references
+    public var identifier: String {
+        switch self {
+        case .openRouter:
+            return "openrouter"
+        case .openAI:
+            return "openai"
+        case .anthropic:
+            return "anthropic"
+        case .custom:
+            return "custom"
+        }
+    }
+
     /// The base URL for API requests
     public var baseURL: URL {
         switch self {
@@ -67,6 +81,11 @@ public enum LLMProvider: Sendable {
         }
     }
 
+    /// Whether requests accept a sampling `seed`
+    public var supportsSeed: Bool {
+        isOpenAICompatible
+    }
+
     /// The chat completions endpoint path
     public var chatCompletionsPath: String {
         switch self {
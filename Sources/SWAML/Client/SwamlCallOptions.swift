@@ -0,0 +1,65 @@
+import Foundation
+
+/// Provider-agnostic sampling options for a single call.
+///
+/// Unset fields fall back to the client's defaults (see `SwamlClient.setDefaultOptions(_:)`),
+/// then to the provider's own defaults. Frequency and presence penalties are only sent to
+/// OpenAI-compatible providers.
+public struct SwamlCallOptions: Sendable, Equatable {
+    public var temperature: Double?
+    public var maxTokens: Int?
+    public var topP: Double?
+    public var frequencyPenalty: Double?
+    public var presencePenalty: Double?
+    public var stop: [String]?
+
+    public init(
+        temperature: Double? = nil,
+        maxTokens: Int? = nil,
+        topP: Double? = nil,
+        frequencyPenalty: Double? = nil,
+        presencePenalty: Double? = nil,
+        stop: [String]? = nil
+    ) {
+        self.temperature = temperature
+        self.maxTokens = maxTokens
+        self.topP = topP
+        self.frequencyPenalty = frequencyPenalty
+        self.presencePenalty = presencePenalty
+        self.stop = stop
+    }
+
+    /// Returns these options with every field set in `overrides` replaced
+    public func merging(_ overrides: SwamlCallOptions) -> SwamlCallOptions {
+        SwamlCallOptions(
+            temperature: overrides.temperature ?? temperature,
+            maxTokens: overrides.maxTokens ?? maxTokens,
+            topP: overrides.topP ?? topP,
+            frequencyPenalty: overrides.frequencyPenalty ?? frequencyPenalty,
+            presencePenalty: overrides.presencePenalty ?? presencePenalty,
+            stop: overrides.stop ?? stop
+        )
+    }
+}
+
+extension LLMClient {
+    /// Send a chat completion request using call options
+    func complete(
+        model: String,
+        messages: [ChatMessage],
+        responseFormat: ResponseFormat? = nil,
+        options: SwamlCallOptions
+    ) async throws -> LLMResponse {
+        try await complete(
+            model: model,
+            messages: messages,
+            responseFormat: responseFormat,
+            temperature: options.temperature,
+            maxTokens: options.maxTokens,
+            topP: options.topP,
+            frequencyPenalty: options.frequencyPenalty,
+            presencePenalty: options.presencePenalty,
+            stop: options.stop
+        )
+    }
+}
@@ -0,0 +1,218 @@
+import Foundation
+#if canImport(FoundationNetworking)
+import FoundationNetworking
+#endif
+#if canImport(Security) && canImport(CryptoKit)
+import Security
+import CryptoKit
+#endif
+
+/// TLS trust settings for a single client.
+///
+/// Use this to trust a corporate root CA (e.g. for a TLS-inspecting proxy) or to pin
+/// the provider's public key. Custom trust evaluation requires the Security
+/// framework; on other platforms requests made with a TLSConfig fail with a
+/// configuration error rather than silently skipping the checks.
+public struct TLSConfig: Sendable, Equatable {
+    /// Additional trusted root certificates (DER-encoded), used alongside the system roots
+    public let additionalRootCertificates: [Data]
+
+    /// Base64-encoded SHA-256 hashes of the leaf certificate's SubjectPublicKeyInfo (DER),
+    /// the same pins curl's `--pinnedpubkey` and most pinning tools use.
+    /// An optional "sha256/" prefix is ignored. Empty means no pinning.
+    public let pinnedPublicKeyHashes: Set<String>
+
+    public init(
+        additionalRootCertificates: [Data] = [],
+        pinnedPublicKeyHashes: Set<String> = []
+    ) {
+        self.additionalRootCertificates = additionalRootCertificates
+        self.pinnedPublicKeyHashes = Set(pinnedPublicKeyHashes.map(TLSConfig.normalizePin))
+    }
+
+    /// Create with root certificates loaded from a PEM bundle on disk
+    public init(
+        caBundleAt url: URL,
+        pinnedPublicKeyHashes: Set<String> = []
+    ) throws {
+        let pem: String
+        do {
+            pem = try String(contentsOf: url, encoding: .utf8)
+        } catch {
+            throw SwamlError.configurationError("Cannot read CA bundle at \(url.path): \(error.localizedDescription)")
+        }
+
+        let certificates = TLSConfig.certificates(fromPEM: pem)
+        guard !certificates.isEmpty else {
+            throw SwamlError.configurationError("No certificates found in CA bundle at \(url.path)")
+        }
+
+        self.init(
+            additionalRootCertificates: certificates,
+            pinnedPublicKeyHashes: pinnedPublicKeyHashes
+        )
+    }
+
+    /// Decode every CERTIFICATE block in a PEM string to DER data
+    public static func certificates(fromPEM pem: String) -> [Data] {
+        var certificates: [Data] = []
+        var base64: String?
+
+        for line in pem.split(whereSeparator: \.isNewline) {
+            let trimmed = line.trimmingCharacters(in: .whitespaces)
+            if trimmed == "-----BEGIN CERTIFICATE-----" {
+                base64 = ""
+            } else if trimmed == "-----END CERTIFICATE-----" {
+                if let encoded = base64, let der = Data(base64Encoded: encoded) {
+                    certificates.append(der)
+                }
+                base64 = nil
+            } else if base64 != nil {
+                base64? += trimmed
+            }
+        }
+
+        return certificates
+    }
+
+    /// Whether a public key hash satisfies the pin set
+    public func isPinned(publicKeyHash: String) -> Bool {
+        pinnedPublicKeyHashes.isEmpty || pinnedPublicKeyHashes.contains(TLSConfig.normalizePin(publicKeyHash))
+    }
+
+    /// Extract the DER-encoded SubjectPublicKeyInfo from a DER-encoded X.509 certificate
+    ///
+    /// - Returns: nil if the certificate is malformed
+    public static func subjectPublicKeyInfo(inCertificate der: Data) -> Data? {
+        let bytes = [UInt8](der)
+
+        // Certificate ::= SEQUENCE { tbsCertificate SEQUENCE { ... }, ... }
+        guard let certificate = DERElement(in: bytes, at: 0), certificate.tag == 0x30,
+              let tbs = DERElement(in: bytes, at: certificate.contentStart), tbs.tag == 0x30,
+              var element = DERElement(in: bytes, at: tbs.contentStart) else {
+            return nil
+        }
+
+        // Skip the optional [0] version, then serial, signature, issuer, validity and subject
+        if element.tag == 0xA0 {
+            guard let next = DERElement(in: bytes, at: element.end) else { return nil }
+            element = next
+        }
+        for _ in 0..<5 {
+            guard element.end < tbs.end, let next = DERElement(in: bytes, at: element.end) else {
+                return nil
+            }
+            element = next
+        }
+
+        guard element.tag == 0x30, element.end <= tbs.end else {
+            return nil
+        }
+        return Data(bytes[element.start..<element.end])
+    }
+
+    private static func normalizePin(_ pin: String) -> String {
+        let trimmed = pin.trimmingCharacters(in: .whitespaces)
+        if trimmed.lowercased().hasPrefix("sha256/") {
+            return String(trimmed.dropFirst("sha256/".count))
+        }
+        return trimmed
+    }
+}
+
+// MARK: - DER
+
+/// A single DER tag-length-value element
+private struct DERElement {
+    let tag: UInt8
+    let start: Int
+    let contentStart: Int
+    let end: Int
+
+    init?(in bytes: [UInt8], at offset: Int) {
+        guard offset + 1 < bytes.count else { return nil }
+        let tag = bytes[offset]
+        var index = offset + 1
+
+        var length = Int(bytes[index])
+        index += 1
+        if length & 0x80 != 0 {
+            // Long form: the low bits give the number of length bytes
+            let count = length & 0x7F
+            guard count > 0, count <= 4, index + count <= bytes.count else { return nil }
+            length = 0
+            for byte in bytes[index..<index + count] {
+                length = length << 8 | Int(byte)
+            }
+            index += count
+        }
+
+        guard index + length <= bytes.count else { return nil }
+        self.tag = tag
+        self.start = offset
+        self.contentStart = index
+        self.end = index + length
+    }
+}
+
+// MARK: - Trust Evaluation
+
+#if canImport(Security) && canImport(CryptoKit)
+extension TLSConfig {
+    /// Evaluate a server trust against the extra roots and public key pins
+    func evaluate(_ trust: SecTrust) -> Bool {
+        if !additionalRootCertificates.isEmpty {
+            let anchors = additionalRootCertificates.compactMap {
+                SecCertificateCreateWithData(nil, $0 as CFData)
+            }
+            SecTrustSetAnchorCertificates(trust, anchors as CFArray)
+            // Keep trusting the system roots as well
+            SecTrustSetAnchorCertificatesOnly(trust, false)
+        }
+
+        guard SecTrustEvaluateWithError(trust, nil) else {
+            return false
+        }
+
+        if pinnedPublicKeyHashes.isEmpty {
+            return true
+        }
+
+        guard let chain = SecTrustCopyCertificateChain(trust) as? [SecCertificate],
+              let leaf = chain.first,
+              let publicKeyInfo = TLSConfig.subjectPublicKeyInfo(inCertificate: SecCertificateCopyData(leaf) as Data) else {
+            return false
+        }
+
+        let hash = Data(SHA256.hash(data: publicKeyInfo)).base64EncodedString()
+        return isPinned(publicKeyHash: hash)
+    }
+}
+
+/// URLSession delegate that applies a TLSConfig to server trust challenges
+final class TLSTrustDelegate: NSObject, URLSessionDelegate, @unchecked Sendable {
+    let tls: TLSConfig
+
+    init(tls: TLSConfig) {
+        self.tls = tls
+    }
+
+    func urlSession(
+        _ session: URLSession,
+        didReceive challenge: URLAuthenticationChallenge,
+        completionHandler: @escaping (URLSession.AuthChallengeDisposition, URLCredential?) -> Void
+    ) {
+        guard challenge.protectionSpace.authenticationMethod == NSURLAuthenticationMethodServerTrust,
+              let trust = challenge.protectionSpace.serverTrust else {
+            completionHandler(.performDefaultHandling, nil)
+            return
+        }
+
+        if tls.evaluate(trust) {
+            completionHandler(.useCredential, URLCredential(trust: trust))
+        } else {
+            completionHandler(.cancelAuthenticationChallenge, nil)
+        }
+    }
+}
+#endif
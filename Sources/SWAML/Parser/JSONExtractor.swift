@@ -1,78 +1,187 @@
 import Foundation
 
+/// How to locate JSON in LLM output that isn't pure JSON
+public enum ExtractionStrategy: String, Sendable, Equatable {
+    /// Fenced code blocks first, then the first JSON object or array in the text
+    case preferFenced
+    /// The last JSON object or array in the text (e.g. a final answer after examples)
+    case lastJSON
+    /// The first JSON object or array anywhere in the text, ignoring code fences
+    case fullScan
+}
+
+/// Where extracted JSON came from, for diagnostics
+public struct ExtractionResult: Sendable, Equatable {
+    public enum Source: String, Sendable, Equatable {
+        /// The whole output was valid JSON
+        case entireOutput
+        /// A fenced markdown code block
+        case codeBlock
+        /// A JSON structure embedded in surrounding text
+        case embedded
+    }
+
+    /// The extracted JSON text
+    public let json: String
+
+    /// Where the JSON was found
+    public let source: Source
+
+    /// Character offset of the JSON within the trimmed output
+    public let offset: Int
+
+    /// Number of valid candidates the strategy found
+    public let candidateCount: Int
+
+    public init(json: String, source: Source, offset: Int, candidateCount: Int) {
+        self.json = json
+        self.source = source
+        self.offset = offset
+        self.candidateCount = candidateCount
+    }
+}
+
 /// Extracts JSON from LLM output that may contain markdown or other formatting
 public struct JSONExtractor {
 
     /// Extract JSON from raw LLM output
     /// Handles common cases like markdown code blocks, extra text before/after JSON
-    public static func extract(from output: String) throws -> String {
+    public static func extract(
+        from output: String,
+        strategy: ExtractionStrategy = .preferFenced
+    ) throws -> String {
+        try extractWithDiagnostics(from: output, strategy: strategy).json
+    }
+
+    /// Extract JSON from raw LLM output, reporting which candidate was chosen
+    public static func extractWithDiagnostics(
+        from output: String,
+        strategy: ExtractionStrategy = .preferFenced
+    ) throws -> ExtractionResult {
         let trimmed = output.trimmingCharacters(in: .whitespacesAndNewlines)
 
         // Try to parse as-is first
         if isValidJSON(trimmed) {
-            return trimmed
+            return ExtractionResult(json: trimmed, source: .entireOutput, offset: 0, candidateCount: 1)
         }
 
-        // Try extracting from markdown code block
-        if let extracted = extractFromMarkdownCodeBlock(trimmed) {
-            return extracted
-        }
+        switch strategy {
+        case .preferFenced:
+            // Try extracting from markdown code block
+            if let extracted = extractFromMarkdownCodeBlock(trimmed) {
+                return extracted
+            }
+
+            // Try finding JSON object or array
+            if let extracted = extractJSONStructure(from: trimmed) {
+                return extracted
+            }
+
+        case .lastJSON:
+            let candidates = jsonCandidates(in: trimmed)
+            if let last = candidates.last {
+                return ExtractionResult(
+                    json: last.json,
+                    source: .embedded,
+                    offset: last.offset,
+                    candidateCount: candidates.count
+                )
+            }
 
-        // Try finding JSON object or array
-        if let extracted = extractJSONStructure(from: trimmed) {
-            return extracted
+        case .fullScan:
+            let candidates = jsonCandidates(in: trimmed)
+            if let first = candidates.first {
+                return ExtractionResult(
+                    json: first.json,
+                    source: .embedded,
+                    offset: first.offset,
+                    candidateCount: candidates.count
+                )
+            }
         }
 
         throw SwamlError.jsonExtractionError("Could not find valid JSON in output")
     }
 
     /// Extract from markdown code block (```json ... ``` or ``` ... ```)
-    private static func extractFromMarkdownCodeBlock(_ text: String) -> String? {
+    private static func extractFromMarkdownCodeBlock(_ text: String) -> ExtractionResult? {
         // Pattern for ```json ... ``` or ``` ... ```
         let patterns = [
             #"```json\s*([\s\S]*?)\s*```"#,
             #"```\s*([\s\S]*?)\s*```"#
         ]
 
+        var blocks: [(json: String, offset: Int)] = []
         for pattern in patterns {
-            if let regex = try? NSRegularExpression(pattern: pattern, options: []),
-               let match = regex.firstMatch(in: text, options: [], range: NSRange(text.startIndex..., in: text)),
-               let range = Range(match.range(at: 1), in: text) {
+            guard let regex = try? NSRegularExpression(pattern: pattern, options: []) else { continue }
+            for match in regex.matches(in: text, options: [], range: NSRange(text.startIndex..., in: text)) {
+                guard let range = Range(match.range(at: 1), in: text) else { continue }
                 let extracted = String(text[range]).trimmingCharacters(in: .whitespacesAndNewlines)
                 if isValidJSON(extracted) {
-                    return extracted
+                    blocks.append((extracted, text.distance(from: text.startIndex, to: range.lowerBound)))
                 }
             }
+            if !blocks.isEmpty {
+                break
+            }
         }
 
-        return nil
+        guard let first = blocks.first else {
+            return nil
+        }
+        return ExtractionResult(json: first.json, source: .codeBlock, offset: first.offset, candidateCount: blocks.count)
     }
 
     /// Find JSON object {...} or array [...] in text
-    private static func extractJSONStructure(from text: String) -> String? {
-        // Try to find JSON object
-        if let objectRange = findBalancedBraces(in: text, open: "{", close: "}") {
-            let extracted = String(text[objectRange])
-            if isValidJSON(extracted) {
-                return extracted
+    private static func extractJSONStructure(from text: String) -> ExtractionResult? {
+        // Try to find JSON object, then JSON array
+        for (open, close) in [(Character("{"), Character("}")), (Character("["), Character("]"))] {
+            guard let start = text.firstIndex(of: open),
+                  let range = findBalancedBraces(in: text, from: start, open: open, close: close) else {
+                continue
             }
-        }
-
-        // Try to find JSON array
-        if let arrayRange = findBalancedBraces(in: text, open: "[", close: "]") {
-            let extracted = String(text[arrayRange])
+            let extracted = String(text[range])
             if isValidJSON(extracted) {
-                return extracted
+                let offset = text.distance(from: text.startIndex, to: range.lowerBound)
+                return ExtractionResult(json: extracted, source: .embedded, offset: offset, candidateCount: 1)
             }
         }
 
         return nil
     }
 
-    /// Find range of balanced braces/brackets
-    private static func findBalancedBraces(in text: String, open: Character, close: Character) -> Range<String.Index>? {
-        guard let startIndex = text.firstIndex(of: open) else { return nil }
+    /// All top-level JSON objects and arrays in text, in order of appearance
+    private static func jsonCandidates(in text: String) -> [(json: String, offset: Int)] {
+        var candidates: [(json: String, offset: Int)] = []
+        var index = text.startIndex
+
+        while index < text.endIndex {
+            let char = text[index]
+            let close: Character? = char == "{" ? "}" : (char == "[" ? "]" : nil)
+
+            if let close = close,
+               let range = findBalancedBraces(in: text, from: index, open: char, close: close) {
+                let candidate = String(text[range])
+                if isValidJSON(candidate) {
+                    candidates.append((candidate, text.distance(from: text.startIndex, to: index)))
+                    index = range.upperBound
+                    continue
+                }
+            }
+
+            index = text.index(after: index)
+        }
+
+        return candidates
+    }
 
+    /// Find range of balanced braces/brackets starting at `startIndex`
+    private static func findBalancedBraces(
+        in text: String,
+        from startIndex: String.Index,
+        open: Character,
+        close: Character
+    ) -> Range<String.Index>? {
         var depth = 0
         var inString = false
         var escapeNext = false
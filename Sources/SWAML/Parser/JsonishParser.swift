@@ -49,6 +49,9 @@ public struct JsonishParser {
     }
 
     /// Parse LLM output with streaming support
+    ///
+    /// Re-parses the whole input on each call. For long streams, use `JsonishStreamParser`,
+    /// which only scans newly appended text.
     public static func parseStreaming(_ input: String, isDone: Bool) throws -> String {
         try parse(input, isDone: isDone)
     }
@@ -517,7 +520,7 @@ public struct JsonishParser {
     }
 
     /// Check if string is valid JSON
-    private static func isValidJSON(_ string: String) -> Bool {
+    static func isValidJSON(_ string: String) -> Bool {
         guard let data = string.data(using: .utf8) else { return false }
         return (try? JSONSerialization.jsonObject(with: data, options: [])) != nil
     }
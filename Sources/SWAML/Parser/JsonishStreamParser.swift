@@ -0,0 +1,227 @@
+import Foundation
+
+/// Incremental parser for streamed LLM output.
+///
+/// `JsonishParser.parseStreaming` re-parses the whole accumulated response on every chunk,
+/// which is quadratic for long outputs. This parser keeps its scanner state between chunks,
+/// so `append` only scans the appended text. It tracks open containers and the last point
+/// where the structure can be closed cleanly.
+///
+/// Snapshots are built only when `currentJSON()` is called, and each call costs time linear in
+/// the output so far. Call it when you are about to render, not on every chunk. Standard JSON
+/// takes the fast path. Output that needs repair (comments, single quotes, unquoted keys, ...)
+/// falls back to `JsonishParser`, so results match the batch parser.
+///
+/// ```swift
+/// var parser = JsonishStreamParser()
+/// for try await chunk in stream {
+///     parser.append(chunk)
+///     if shouldRender, let partial = parser.currentJSON() {
+///         render(partial)
+///     }
+/// }
+/// let json = try parser.finish()
+/// ```
+public struct JsonishStreamParser {
+    /// All text received so far
+    public private(set) var buffer: String = ""
+
+    /// Whether the root JSON value has been closed
+    public private(set) var isComplete = false
+
+    /// Bytes from the first `{` or `[` onward
+    private var json: [UInt8] = []
+    private var started = false
+
+    /// Closing characters for the currently open containers
+    private var stack: [UInt8] = []
+    private var inString = false
+    private var inKey = false
+    private var escapeNext = false
+    private var lastEscape: Int?
+    private var inScalar = false
+    private var lastSignificant: UInt8 = 0
+
+    /// Length of `json` and open containers at the last clean cut point
+    private var safeLength = 0
+    private var safeStack: [UInt8] = []
+
+    public init() {}
+
+    /// Append a chunk. Only the new text is scanned.
+    public mutating func append(_ chunk: String) {
+        buffer += chunk
+        if !isComplete {
+            scan(chunk.utf8)
+        }
+    }
+
+    /// Best-effort JSON for the text received so far, with open structures closed
+    ///
+    /// Builds and validates a snapshot of everything received, so avoid calling it per chunk
+    /// on long outputs.
+    public func currentJSON() -> String? {
+        guard started else {
+            return nil
+        }
+
+        if let optimistic = optimisticSnapshot(), JsonishParser.isValidJSON(optimistic) {
+            return optimistic
+        }
+
+        let safe = String(decoding: json[..<safeLength], as: UTF8.self) + closers(for: safeStack)
+        if JsonishParser.isValidJSON(safe) {
+            return safe
+        }
+
+        return try? JsonishParser.parse(buffer, isDone: false)
+    }
+
+    /// Final JSON once the stream has ended
+    ///
+    /// - Throws: SwamlError.parseError if no valid JSON can be extracted
+    public func finish() throws -> String {
+        if isComplete {
+            let text = String(decoding: json, as: UTF8.self)
+            if JsonishParser.isValidJSON(text) {
+                return text
+            }
+        }
+        return try JsonishParser.parse(buffer, isDone: true)
+    }
+
+    // MARK: - Scanning
+
+    private mutating func scan<Bytes: Sequence>(_ bytes: Bytes) where Bytes.Element == UInt8 {
+        for byte in bytes {
+            if isComplete {
+                // Ignore trailing prose or closing code fences
+                return
+            }
+
+            if !started {
+                guard byte == Byte.openBrace || byte == Byte.openBracket else {
+                    continue
+                }
+                started = true
+            }
+
+            json.append(byte)
+
+            if inString {
+                scanStringByte(byte)
+                continue
+            }
+
+            switch byte {
+            case Byte.quote:
+                inString = true
+                inKey = stack.last == Byte.closeBrace &&
+                    (lastSignificant == Byte.openBrace || lastSignificant == Byte.comma)
+                lastEscape = nil
+
+            case Byte.openBrace, Byte.openBracket:
+                stack.append(byte == Byte.openBrace ? Byte.closeBrace : Byte.closeBracket)
+                lastSignificant = byte
+                markSafe()
+
+            case Byte.closeBrace, Byte.closeBracket:
+                inScalar = false
+                if stack.last == byte {
+                    stack.removeLast()
+                }
+                lastSignificant = byte
+                markSafe()
+                if stack.isEmpty {
+                    isComplete = true
+                }
+
+            case Byte.comma:
+                endScalar()
+                lastSignificant = byte
+
+            case Byte.colon:
+                lastSignificant = byte
+
+            case Byte.space, Byte.newline, Byte.carriageReturn, Byte.tab:
+                endScalar()
+
+            default:
+                inScalar = true
+                lastSignificant = byte
+            }
+        }
+    }
+
+    private mutating func scanStringByte(_ byte: UInt8) {
+        if escapeNext {
+            escapeNext = false
+            return
+        }
+
+        switch byte {
+        case Byte.backslash:
+            escapeNext = true
+            lastEscape = json.count - 1
+        case Byte.quote:
+            inString = false
+            lastSignificant = byte
+            if !inKey {
+                markSafe()
+            }
+        default:
+            break
+        }
+    }
+
+    /// A scalar (number, true, false, null) ended just before the current byte
+    private mutating func endScalar() {
+        guard inScalar else { return }
+        inScalar = false
+        safeLength = json.count - 1
+        safeStack = stack
+    }
+
+    private mutating func markSafe() {
+        safeLength = json.count
+        safeStack = stack
+    }
+
+    // MARK: - Snapshots
+
+    /// Everything received so far, with an open value string and containers closed
+    private func optimisticSnapshot() -> String? {
+        if inString {
+            guard !inKey else { return nil }
+
+            // Drop an escape sequence that may still be incomplete (e.g. "\u00")
+            var cut = json.count
+            if let escape = lastEscape, json.count - escape <= 6 {
+                cut = escape
+            }
+            return String(decoding: json[..<cut], as: UTF8.self) + "\"" + closers(for: stack)
+        }
+
+        return String(decoding: json, as: UTF8.self) + closers(for: stack)
+    }
+
+    private func closers(for stack: [UInt8]) -> String {
+        String(decoding: stack.reversed(), as: UTF8.self)
+    }
+}
+
+/// ASCII bytes used by the stream scanner
+private enum Byte {
+    static let openBrace = UInt8(ascii: "{")
+    static let closeBrace = UInt8(ascii: "}")
+    static let openBracket = UInt8(ascii: "[")
+    static let closeBracket = UInt8(ascii: "]")
+    static let quote = UInt8(ascii: "\"")
+    static let backslash = UInt8(ascii: "\\")
+    static let comma = UInt8(ascii: ",")
+    static let colon = UInt8(ascii: ":")
+    static let space = UInt8(ascii: " ")
+    static let newline = UInt8(ascii: "\n")
+    static let carriageReturn = UInt8(ascii: "\r")
+    static let tab = UInt8(ascii: "\t")
+}
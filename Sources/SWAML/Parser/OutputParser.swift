@@ -1,5 +1,14 @@
 import Foundation
 
+/// A parsed value with extraction diagnostics
+public struct ParsedOutput: Sendable {
+    /// The parsed (and coerced) value
+    public let value: SwamlValue
+
+    /// Which part of the output the JSON came from
+    public let extraction: ExtractionResult
+}
+
 /// Parses LLM output into typed values
 public struct OutputParser {
 
@@ -7,18 +16,18 @@ public struct OutputParser {
     public static func parse<T: Codable>(
         _ output: String,
         schema: JSONSchema? = nil,
-        type: T.Type
+        type: T.Type,
+        options: CoercionOptions = .default,
+        extraction: ExtractionStrategy = .preferFenced
     ) throws -> T {
         // Extract JSON from potentially wrapped output
-        let jsonString = try JSONExtractor.extract(from: output)
+        let jsonString = try JSONExtractor.extract(from: output, strategy: extraction)
 
         // Get JSON data for decoding
         let data: Data
         if let schema = schema {
             // Parse to SwamlValue for coercion
-            var swamlValue = try SwamlValue.fromJSONString(jsonString)
-            swamlValue = try applySchemaCoercion(swamlValue, schema: schema)
-            let coercedJSON = try swamlValue.toJSONString()
+            let coercedJSON = try coerce(jsonString, schema: schema, options: options).toJSONString()
             guard let d = coercedJSON.data(using: .utf8) else {
                 throw SwamlError.parseError("Failed to convert to UTF-8")
             }
@@ -31,6 +40,38 @@ public struct OutputParser {
             data = d
         }
 
+        return try decode(data, as: T.self)
+    }
+
+    /// Extract and coerce output the same way `parse(_:schema:type:options:extraction:)` does,
+    /// stopping before decoding. Unlike `parseToValue`, the value is not validated against the
+    /// schema.
+    static func coercedValue(
+        _ output: String,
+        schema: JSONSchema?,
+        options: CoercionOptions = .default,
+        extraction: ExtractionStrategy = .preferFenced
+    ) throws -> SwamlValue {
+        let jsonString = try JSONExtractor.extract(from: output, strategy: extraction)
+        guard let schema = schema else {
+            return try SwamlValue.fromJSONString(jsonString)
+        }
+        return try coerce(jsonString, schema: schema, options: options)
+    }
+
+    private static func coerce(_ jsonString: String, schema: JSONSchema, options: CoercionOptions) throws -> SwamlValue {
+        try applySchemaCoercion(SwamlValue.fromJSONString(jsonString), schema: schema, options: options)
+    }
+
+    /// Decode an already-parsed value into a typed output
+    public static func decode<T: Codable>(_ value: SwamlValue, as type: T.Type) throws -> T {
+        guard let data = try value.toJSONString().data(using: .utf8) else {
+            throw SwamlError.parseError("Failed to convert to UTF-8")
+        }
+        return try decode(data, as: type)
+    }
+
+    private static func decode<T: Codable>(_ data: Data, as type: T.Type) throws -> T {
         // Try decoding with snake_case conversion first, then without
         do {
             let decoder = JSONDecoder()
@@ -48,31 +89,48 @@ public struct OutputParser {
     }
 
     /// Parse raw output to SwamlValue with schema validation
-    public static func parseToValue(_ output: String, schema: JSONSchema? = nil) throws -> SwamlValue {
-        let jsonString = try JSONExtractor.extract(from: output)
-        var swamlValue = try SwamlValue.fromJSONString(jsonString)
+    public static func parseToValue(
+        _ output: String,
+        schema: JSONSchema? = nil,
+        options: CoercionOptions = .default,
+        extraction: ExtractionStrategy = .preferFenced
+    ) throws -> SwamlValue {
+        try parseWithDiagnostics(output, schema: schema, options: options, extraction: extraction).value
+    }
+
+    /// Parse raw output to SwamlValue, reporting where the JSON was found
+    public static func parseWithDiagnostics(
+        _ output: String,
+        schema: JSONSchema? = nil,
+        options: CoercionOptions = .default,
+        extraction: ExtractionStrategy = .preferFenced
+    ) throws -> ParsedOutput {
+        let result = try JSONExtractor.extractWithDiagnostics(from: output, strategy: extraction)
+        var swamlValue = try SwamlValue.fromJSONString(result.json)
 
         if let schema = schema {
-            swamlValue = try applySchemaCoercion(swamlValue, schema: schema)
+            swamlValue = try applySchemaCoercion(swamlValue, schema: schema, options: options)
             try validateAgainstSchema(swamlValue, schema: schema)
         }
 
-        return swamlValue
+        return ParsedOutput(value: swamlValue, extraction: result)
     }
 
     /// Parse with repair attempts for malformed JSON
     public static func parseWithRepair<T: Codable>(
         _ output: String,
         schema: JSONSchema? = nil,
-        type: T.Type
+        type: T.Type,
+        options: CoercionOptions = .default,
+        extraction: ExtractionStrategy = .preferFenced
     ) throws -> T {
         // First try normal parsing
         do {
-            return try parse(output, schema: schema, type: type)
+            return try parse(output, schema: schema, type: type, options: options, extraction: extraction)
         } catch {
             // Try to repair the JSON
             if let repaired = JSONExtractor.repair(output) {
-                return try parse(repaired, schema: schema, type: type)
+                return try parse(repaired, schema: schema, type: type, options: options, extraction: extraction)
             }
             throw error
         }
@@ -80,16 +138,20 @@ public struct OutputParser {
 
     // MARK: - Schema Coercion
 
-    private static func applySchemaCoercion(_ value: SwamlValue, schema: JSONSchema) throws -> SwamlValue {
+    private static func applySchemaCoercion(
+        _ value: SwamlValue,
+        schema: JSONSchema,
+        options: CoercionOptions
+    ) throws -> SwamlValue {
         switch schema {
         case .string:
-            return try TypeCoercion.coerce(value, to: FieldType.string)
+            return try TypeCoercion.coerce(value, to: FieldType.string, options: options)
         case .integer:
-            return try TypeCoercion.coerce(value, to: FieldType.int)
+            return try TypeCoercion.coerce(value, to: FieldType.int, options: options)
         case .number:
-            return try TypeCoercion.coerce(value, to: FieldType.float)
+            return try TypeCoercion.coerce(value, to: FieldType.float, options: options)
         case .boolean:
-            return try TypeCoercion.coerce(value, to: FieldType.bool)
+            return try TypeCoercion.coerce(value, to: FieldType.bool, options: options)
         case .null:
             if value.isNull {
                 return value
@@ -99,28 +161,40 @@ public struct OutputParser {
             guard case .array(let elements) = value else {
                 throw SwamlError.typeCoercionError(expected: "array", actual: value.typeName)
             }
-            let coercedElements = try elements.map { try applySchemaCoercion($0, schema: items) }
+            let coercedElements = try elements.map { try applySchemaCoercion($0, schema: items, options: options) }
             return .array(coercedElements)
-        case .object(let properties, _, _):
+        case .object(let properties, _, let additionalProperties):
             guard case .map(var dict) = value else {
                 throw SwamlError.typeCoercionError(expected: "object", actual: value.typeName)
             }
+            if options.rejectUnknownFields, additionalProperties == nil {
+                if let unknown = dict.keys.sorted().first(where: { properties[$0] == nil }) {
+                    throw SwamlError.schemaValidationError("Unknown property: \(unknown)")
+                }
+            }
             for (key, propSchema) in properties {
                 if let propValue = dict[key] {
-                    dict[key] = try applySchemaCoercion(propValue, schema: propSchema)
+                    dict[key] = try applySchemaCoercion(propValue, schema: propSchema, options: options)
                 }
             }
             return .map(dict)
-        case .enum:
+        case .enum(let values):
             // Enum values should be strings
-            return try TypeCoercion.coerce(value, to: FieldType.string)
+            let coerced = try TypeCoercion.coerce(value, to: FieldType.string, options: options)
+            if options.caseInsensitiveEnums, let raw = coerced.stringValue {
+                let normalized = raw.trimmingCharacters(in: .whitespacesAndNewlines).lowercased()
+                if let match = values.first(where: { $0.lowercased() == normalized }) {
+                    return .string(match)
+                }
+            }
+            return coerced
         case .ref:
             // References are resolved at a higher level
             return value
         case .anyOf(let schemas):
             // Try each schema until one works
             for subSchema in schemas {
-                if let coerced = try? applySchemaCoercion(value, schema: subSchema) {
+                if let coerced = try? applySchemaCoercion(value, schema: subSchema, options: options) {
                     return coerced
                 }
             }
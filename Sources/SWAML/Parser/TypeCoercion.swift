@@ -1,19 +1,61 @@
 import Foundation
 
+/// Controls how strictly parsed output is coerced to the expected schema.
+///
+/// The defaults are lenient and fix common LLM mistakes. Use `.strict` in high-stakes
+/// pipelines to get errors instead of silent best-effort fixes.
+public struct CoercionOptions: Sendable, Equatable {
+    /// Convert between strings, numbers and booleans (e.g. "42" → 42, 1 → true, 3 → "3").
+    /// Whole-number floats still become ints when this is off.
+    public var allowImplicitConversions: Bool
+
+    /// Reject object properties the schema does not declare
+    /// (objects with `additionalProperties` accept any key)
+    public var rejectUnknownFields: Bool
+
+    /// Accept enum values that differ only in case or surrounding whitespace,
+    /// normalizing them to the declared spelling
+    public var caseInsensitiveEnums: Bool
+
+    public init(
+        allowImplicitConversions: Bool = true,
+        rejectUnknownFields: Bool = false,
+        caseInsensitiveEnums: Bool = false
+    ) {
+        self.allowImplicitConversions = allowImplicitConversions
+        self.rejectUnknownFields = rejectUnknownFields
+        self.caseInsensitiveEnums = caseInsensitiveEnums
+    }
+
+    /// Lenient coercion (the default behavior)
+    public static let `default` = CoercionOptions()
+
+    /// No implicit conversions, no unknown fields, exact enum matches
+    public static let strict = CoercionOptions(
+        allowImplicitConversions: false,
+        rejectUnknownFields: true,
+        caseInsensitiveEnums: false
+    )
+}
+
 /// Handles coercion of values to expected types
 public struct TypeCoercion {
 
     /// Coerce a SwamlValue to match expected type in schema
-    public static func coerce(_ value: SwamlValue, to type: FieldType) throws -> SwamlValue {
+    public static func coerce(
+        _ value: SwamlValue,
+        to type: FieldType,
+        options: CoercionOptions = .default
+    ) throws -> SwamlValue {
         switch type {
         case .string, .literalString:
-            return try coerceToString(value)
+            return try coerceToString(value, options: options)
         case .int, .literalInt:
-            return try coerceToInt(value)
+            return try coerceToInt(value, options: options)
         case .float:
-            return try coerceToFloat(value)
+            return try coerceToFloat(value, options: options)
         case .bool, .literalBool:
-            return try coerceToBool(value)
+            return try coerceToBool(value, options: options)
         case .null:
             if value.isNull {
                 return .null
@@ -23,15 +65,15 @@ public struct TypeCoercion {
             if value.isNull {
                 return .null
             }
-            return try coerce(value, to: inner)
+            return try coerce(value, to: inner, options: options)
         case .list(let element):
-            return try coerceToArray(value, elementType: element)
+            return try coerceToArray(value, elementType: element, options: options)
         case .map(let keyType, let valueType):
-            return try coerceToMap(value, keyType: keyType, valueType: valueType)
+            return try coerceToMap(value, keyType: keyType, valueType: valueType, options: options)
         case .union(let types):
             // Try each type in the union until one succeeds
             for unionType in types {
-                if let result = try? coerce(value, to: unionType) {
+                if let result = try? coerce(value, to: unionType, options: options) {
                     return result
                 }
             }
@@ -44,7 +86,11 @@ public struct TypeCoercion {
 
     // MARK: - String Coercion
 
-    private static func coerceToString(_ value: SwamlValue) throws -> SwamlValue {
+    private static func coerceToString(_ value: SwamlValue, options: CoercionOptions) throws -> SwamlValue {
+        if !options.allowImplicitConversions, value.isNumber || value.isBool {
+            throw SwamlError.typeCoercionError(expected: "string", actual: value.typeName)
+        }
+
         switch value {
         case .string:
             return value
@@ -63,7 +109,11 @@ public struct TypeCoercion {
 
     // MARK: - Int Coercion
 
-    private static func coerceToInt(_ value: SwamlValue) throws -> SwamlValue {
+    private static func coerceToInt(_ value: SwamlValue, options: CoercionOptions) throws -> SwamlValue {
+        if !options.allowImplicitConversions, value.isString || value.isBool {
+            throw SwamlError.typeCoercionError(expected: "int", actual: value.typeName)
+        }
+
         switch value {
         case .int:
             return value
@@ -91,7 +141,11 @@ public struct TypeCoercion {
 
     // MARK: - Float Coercion
 
-    private static func coerceToFloat(_ value: SwamlValue) throws -> SwamlValue {
+    private static func coerceToFloat(_ value: SwamlValue, options: CoercionOptions) throws -> SwamlValue {
+        if !options.allowImplicitConversions, value.isString {
+            throw SwamlError.typeCoercionError(expected: "float", actual: value.typeName)
+        }
+
         switch value {
         case .float:
             return value
@@ -109,7 +163,11 @@ public struct TypeCoercion {
 
     // MARK: - Bool Coercion
 
-    private static func coerceToBool(_ value: SwamlValue) throws -> SwamlValue {
+    private static func coerceToBool(_ value: SwamlValue, options: CoercionOptions) throws -> SwamlValue {
+        if !options.allowImplicitConversions, value.isInt || value.isString {
+            throw SwamlError.typeCoercionError(expected: "bool", actual: value.typeName)
+        }
+
         switch value {
         case .bool:
             return value
@@ -131,18 +189,27 @@ public struct TypeCoercion {
 
     // MARK: - Array Coercion
 
-    private static func coerceToArray(_ value: SwamlValue, elementType: FieldType) throws -> SwamlValue {
+    private static func coerceToArray(
+        _ value: SwamlValue,
+        elementType: FieldType,
+        options: CoercionOptions
+    ) throws -> SwamlValue {
         guard case .array(let elements) = value else {
             throw SwamlError.typeCoercionError(expected: "array", actual: value.typeName)
         }
 
-        let coercedElements = try elements.map { try coerce($0, to: elementType) }
+        let coercedElements = try elements.map { try coerce($0, to: elementType, options: options) }
         return .array(coercedElements)
     }
 
     // MARK: - Map Coercion
 
-    private static func coerceToMap(_ value: SwamlValue, keyType: FieldType, valueType: FieldType) throws -> SwamlValue {
+    private static func coerceToMap(
+        _ value: SwamlValue,
+        keyType: FieldType,
+        valueType: FieldType,
+        options: CoercionOptions
+    ) throws -> SwamlValue {
         guard case .map(let dict) = value else {
             throw SwamlError.typeCoercionError(expected: "map", actual: value.typeName)
         }
@@ -150,7 +217,7 @@ public struct TypeCoercion {
         // Keys must be strings in JSON, so we just validate values
         var coercedDict: [String: SwamlValue] = [:]
         for (key, val) in dict {
-            coercedDict[key] = try coerce(val, to: valueType)
+            coercedDict[key] = try coerce(val, to: valueType, options: options)
         }
         return .map(coercedDict)
     }
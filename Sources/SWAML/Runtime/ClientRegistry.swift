@@ -8,6 +8,9 @@ public struct ClientConfig: Sendable {
     public let retryPolicy: RetryPolicy
     public let defaultTemperature: Double?
     public let defaultMaxTokens: Int?
+    public let httpConfig: HTTPConfig
+    /// Context window override (in tokens); defaults to the known size for `model`
+    public let contextWindow: Int?
 
     public init(
         name: String,
@@ -15,7 +18,9 @@ public struct ClientConfig: Sendable {
         model: String,
         retryPolicy: RetryPolicy = .standard,
         defaultTemperature: Double? = nil,
-        defaultMaxTokens: Int? = nil
+        defaultMaxTokens: Int? = nil,
+        httpConfig: HTTPConfig = .default,
+        contextWindow: Int? = nil
     ) {
         self.name = name
         self.provider = provider
@@ -23,6 +28,27 @@ public struct ClientConfig: Sendable {
         self.retryPolicy = retryPolicy
         self.defaultTemperature = defaultTemperature
         self.defaultMaxTokens = defaultMaxTokens
+        self.httpConfig = httpConfig
+        self.contextWindow = contextWindow
+    }
+
+    /// Context window for this client, if known
+    public var effectiveContextWindow: Int? {
+        contextWindow ?? ContextWindow.tokens(for: model)
+    }
+
+    /// A copy of this configuration that targets a different model
+    public func withModel(_ model: String) -> ClientConfig {
+        ClientConfig(
+            name: name,
+            provider: provider,
+            model: model,
+            retryPolicy: retryPolicy,
+            defaultTemperature: defaultTemperature,
+            defaultMaxTokens: defaultMaxTokens,
+            httpConfig: httpConfig,
+            contextWindow: model == self.model ? contextWindow : nil
+        )
     }
 }
 
@@ -37,6 +63,7 @@ public actor ClientRegistry {
     /// Register a client configuration
     public func register(_ config: ClientConfig, isDefault: Bool = false) {
         clients[config.name] = config
+        llmClients.removeValue(forKey: config.name)
         if isDefault || defaultClientName == nil {
             defaultClientName = config.name
         }
@@ -50,6 +77,8 @@ public actor ClientRegistry {
         retryPolicy: RetryPolicy = .standard,
         defaultTemperature: Double? = nil,
         defaultMaxTokens: Int? = nil,
+        httpConfig: HTTPConfig = .default,
+        contextWindow: Int? = nil,
         isDefault: Bool = false
     ) {
         let config = ClientConfig(
@@ -58,7 +87,9 @@ public actor ClientRegistry {
             model: model,
             retryPolicy: retryPolicy,
             defaultTemperature: defaultTemperature,
-            defaultMaxTokens: defaultMaxTokens
+            defaultMaxTokens: defaultMaxTokens,
+            httpConfig: httpConfig,
+            contextWindow: contextWindow
         )
         register(config, isDefault: isDefault)
     }
@@ -74,6 +105,38 @@ public actor ClientRegistry {
         return clients[name]
     }
 
+    /// Resolve a client reference to a configuration
+    ///
+    /// A reference is either a registered client name or a "provider/model" shorthand such as
+    /// "anthropic/claude-sonnet-4-20250514". The shorthand reuses the first registered client
+    /// for that provider (the default client first) with the model replaced. With an OpenRouter
+    /// default client, any other "vendor/model" is treated as an OpenRouter model ID.
+    public func resolve(_ reference: String) -> ClientConfig? {
+        if let config = clients[reference] {
+            return config
+        }
+
+        guard let slash = reference.firstIndex(of: "/") else {
+            return nil
+        }
+        let providerName = reference[..<slash].lowercased()
+        let model = String(reference[reference.index(after: slash)...])
+        guard !model.isEmpty else {
+            return nil
+        }
+
+        let candidates = ([defaultClientName].compactMap { $0 } + clients.keys.sorted())
+            .compactMap { clients[$0] }
+        if let base = candidates.first(where: { $0.provider.identifier == providerName }) {
+            return base.withModel(model)
+        }
+
+        if let base = getDefaultConfig(), base.provider.identifier == "openrouter" {
+            return base.withModel(reference)
+        }
+        return nil
+    }
+
     /// Get or create an LLMClient for a configuration
     public func getClient(_ name: String) throws -> LLMClient {
         if let existing = llmClients[name] {
@@ -84,7 +147,7 @@ public actor ClientRegistry {
             throw SwamlError.clientNotFound(name)
         }
 
-        let client = LLMClient(provider: config.provider)
+        let client = LLMClient(provider: config.provider, httpConfig: config.httpConfig)
         llmClients[name] = client
         return client
     }
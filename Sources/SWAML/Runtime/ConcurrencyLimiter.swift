@@ -0,0 +1,94 @@
+import Foundation
+
+/// Caps how many calls to each function may run at once.
+///
+/// Calls beyond a function's limit wait in FIFO order until a running call finishes, so an
+/// expensive or hot function cannot use up the provider's rate limit for everything else.
+/// Functions without a limit are not restricted.
+public actor ConcurrencyLimiter {
+    private var limits: [String: Int]
+    private var active: [String: Int] = [:]
+    private var waiters: [String: [(id: UUID, continuation: CheckedContinuation<Void, Error>)]] = [:]
+
+    /// - Parameter limits: Maximum concurrent calls by function name
+    public init(limits: [String: Int] = [:]) {
+        self.limits = limits.mapValues { max($0, 1) }
+    }
+
+    /// Set or remove (nil) the limit for a function
+    ///
+    /// Raising a limit starts waiting calls immediately.
+    public func setLimit(_ limit: Int?, for function: String) {
+        limits[function] = limit.map { max($0, 1) }
+        resumeWaiters(function)
+    }
+
+    /// The limit for a function, or nil if unlimited
+    public func limit(for function: String) -> Int? {
+        limits[function]
+    }
+
+    /// Number of calls to a function currently running
+    public func activeCount(for function: String) -> Int {
+        active[function] ?? 0
+    }
+
+    /// Number of calls to a function waiting for a slot
+    public func queuedCount(for function: String) -> Int {
+        waiters[function]?.count ?? 0
+    }
+
+    // MARK: - Slots
+
+    /// Wait for a slot. Returns true if the call had to queue.
+    ///
+    /// - Throws: CancellationError if the task is cancelled while waiting
+    func acquire(_ function: String) async throws -> Bool {
+        let limit = limits[function] ?? Int.max
+        if (active[function] ?? 0) < limit, waiters[function]?.isEmpty ?? true {
+            active[function, default: 0] += 1
+            return false
+        }
+
+        let id = UUID()
+        try await withTaskCancellationHandler {
+            try await withCheckedThrowingContinuation { (continuation: CheckedContinuation<Void, Error>) in
+                if Task.isCancelled {
+                    continuation.resume(throwing: CancellationError())
+                } else {
+                    waiters[function, default: []].append((id, continuation))
+                }
+            }
+        } onCancel: {
+            Task { await self.cancelWaiter(id, function: function) }
+        }
+        return true
+    }
+
+    /// Release a slot taken by `acquire`
+    func release(_ function: String) {
+        guard let count = active[function], count > 0 else {
+            return
+        }
+        active[function] = count - 1
+        resumeWaiters(function)
+    }
+
+    private func resumeWaiters(_ function: String) {
+        let limit = limits[function] ?? Int.max
+        while (active[function] ?? 0) < limit, var queue = waiters[function], !queue.isEmpty {
+            let next = queue.removeFirst()
+            waiters[function] = queue
+            active[function, default: 0] += 1
+            next.continuation.resume()
+        }
+    }
+
+    private func cancelWaiter(_ id: UUID, function: String) {
+        guard let index = waiters[function]?.firstIndex(where: { $0.id == id }) else {
+            return
+        }
+        let waiter = waiters[function]!.remove(at: index)
+        waiter.continuation.resume(throwing: CancellationError())
+    }
+}
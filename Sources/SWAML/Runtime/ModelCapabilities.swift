@@ -0,0 +1,52 @@
+import Foundation
+
+/// Known input modalities of models
+public enum ModelCapabilities {
+    /// Model name prefixes and whether they accept image input. The longest matching prefix wins.
+    ///
+    /// A `false` entry makes the runtime reject image calls, so only list a prefix as false when
+    /// every model it matches is text-only. Leave uncertain families out; they are not checked.
+    public static let imageInput: [String: Bool] = [
+        "gpt-3.5-turbo": false,
+        "gpt-4-0314": false,
+        "gpt-4-0613": false,
+        "gpt-4-32k": false,
+        "gpt-4-0125-preview": false,
+        "gpt-4-1106-preview": false,
+        "gpt-4-turbo-preview": false,
+        "gpt-4-turbo": true,
+        "gpt-4-vision-preview": true,
+        "gpt-4o": true,
+        "gpt-4.1": true,
+        "gpt-4.5": true,
+        "o1": true,
+        "o1-mini": false,
+        "o1-preview": false,
+        "o3": true,
+        "o3-mini": false,
+        "o4-mini": true,
+        "claude-2": false,
+        "claude-instant": false,
+        "claude-3": true,
+        "claude-opus-4": true,
+        "claude-sonnet-4": true,
+        "gemini": true,
+        "llama-3.2-11b-vision": true,
+        "llama-3.2-90b-vision": true,
+        "mistral-large": false,
+        "mistral-small-3.1": true
+    ]
+
+    /// Whether a model accepts image input, or nil if unknown
+    ///
+    /// Provider prefixes such as "openai/" are ignored.
+    public static func supportsImages(_ model: String) -> Bool? {
+        let name = model.lowercased()
+        let base = name.split(separator: "/").last.map(String.init) ?? name
+
+        return imageInput
+            .filter { base.hasPrefix($0.key) }
+            .max { $0.key.count < $1.key.count }?
+            .value
+    }
+}
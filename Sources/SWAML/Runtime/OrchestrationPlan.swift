@@ -0,0 +1,56 @@
+import Foundation
+
+/// What the runtime will do for a function call, resolved without sending anything.
+///
+/// Returned by `SwamlRuntime.orchestrationPlan(for:ctx:)`. Encode it with `JSONEncoder` to show
+/// users which clients, models and retry settings a call will use.
+public struct OrchestrationPlan: Codable, Sendable, Equatable {
+    /// How the request is dispatched to the nodes
+    public enum Strategy: String, Codable, Sendable {
+        /// Send to the single node
+        case single
+        /// Send to every node at once and keep the first response that parses
+        case race
+    }
+
+    /// One client the request may be sent to
+    public struct Node: Codable, Sendable, Equatable {
+        public let client: String
+        public let provider: String
+        public let model: String
+        public let temperature: Double?
+        public let maxTokens: Int?
+        public let seed: Int?
+        /// Transport retries after the first attempt
+        public let maxRetries: Int
+        public let retryableStatusCodes: [Int]
+        /// Total deadline per attempt, in seconds
+        public let timeout: TimeInterval?
+    }
+
+    public let function: String
+    public let strategy: Strategy
+    public let nodes: [Node]
+    /// Re-asks after responses that fail to parse or validate
+    public let validationRetries: Int
+    /// Maximum concurrent calls to the function, or nil if unlimited
+    public let concurrencyLimit: Int?
+    /// Names of the post-processors applied to the output, in order
+    public let postProcessors: [String]
+}
+
+extension OrchestrationPlan.Node {
+    init(config: ClientConfig, sampling: SamplingSettings, timeout: TimeInterval?) {
+        self.init(
+            client: config.name,
+            provider: config.provider.identifier,
+            model: config.model,
+            temperature: sampling.temperature,
+            maxTokens: sampling.maxTokens,
+            seed: sampling.seed,
+            maxRetries: config.retryPolicy.maxRetries,
+            retryableStatusCodes: config.retryPolicy.retryableStatusCodes.sorted(),
+            timeout: timeout ?? config.httpConfig.totalTimeout
+        )
+    }
+}
@@ -0,0 +1,89 @@
+import Foundation
+
+/// A hook that rewrites a function's parsed output before it is returned.
+///
+/// Post-processors run after parsing, coercion and schema validation, in the order they were
+/// added to the runtime. Use them for cross-cutting cleanup such as PII scrubbing or unit
+/// normalization.
+///
+/// ```swift
+/// await runtime.addPostProcessor(OutputPostProcessor("scrub-emails", functions: "Extract*") { value in
+///     scrubEmails(in: value)
+/// })
+/// ```
+public struct OutputPostProcessor: Sendable {
+    /// Name used when removing the processor and in error messages
+    public let name: String
+
+    /// Function name pattern. `*` matches any run of characters and `?` a single character.
+    public let functionPattern: String
+
+    private let process: @Sendable (SwamlValue) throws -> SwamlValue
+
+    public init(
+        _ name: String,
+        functions pattern: String = "*",
+        process: @escaping @Sendable (SwamlValue) throws -> SwamlValue
+    ) {
+        self.name = name
+        self.functionPattern = pattern
+        self.process = process
+    }
+
+    /// Create a processor that works on a decoded type
+    ///
+    /// The output is decoded as `T`, processed, and encoded back. Outputs that don't decode
+    /// as `T` fail the call with `SwamlError.internalError` naming this processor.
+    public init<T: Codable>(
+        _ name: String,
+        functions pattern: String = "*",
+        as type: T.Type,
+        process: @escaping @Sendable (T) throws -> T
+    ) {
+        self.init(name, functions: pattern) { value in
+            let decoded = try OutputParser.decode(value, as: T.self)
+            let data = try JSONEncoder().encode(try process(decoded))
+            return try SwamlValue.fromJSONString(String(decoding: data, as: UTF8.self))
+        }
+    }
+
+    /// Whether this processor applies to a function
+    public func matches(_ function: String) -> Bool {
+        OutputPostProcessor.glob(Array(functionPattern), matches: Array(function))
+    }
+
+    /// Run the processor on a value
+    public func apply(to value: SwamlValue) throws -> SwamlValue {
+        try process(value)
+    }
+
+    private static func glob(_ pattern: [Character], matches text: [Character]) -> Bool {
+        var p = 0
+        var t = 0
+        var star: Int?
+        var resume = 0
+
+        while t < text.count {
+            if p < pattern.count, pattern[p] == "?" || pattern[p] == text[t] {
+                p += 1
+                t += 1
+            } else if p < pattern.count, pattern[p] == "*" {
+                star = p
+                resume = t
+                p += 1
+            } else if let starIndex = star {
+                // Let the last `*` absorb one more character
+                p = starIndex + 1
+                resume += 1
+                t = resume
+            } else {
+                return false
+            }
+        }
+
+        while p < pattern.count, pattern[p] == "*" {
+            p += 1
+        }
+        return p == pattern.count
+    }
+}
@@ -54,11 +54,19 @@ public struct RetryPolicy: Sendable {
     public func shouldRetry(error: Error, attempt: Int) -> Bool {
         guard attempt < maxRetries else { return false }
 
+        // A cancelled request was abandoned on purpose (e.g. it lost a race); don't reissue it
+        if Task.isCancelled || error is CancellationError {
+            return false
+        }
+        if let urlError = error as? URLError, urlError.code == .cancelled {
+            return false
+        }
+
         if let swamlError = error as? SwamlError {
             switch swamlError {
             case .apiError(let statusCode, _):
                 return retryableStatusCodes.contains(statusCode)
-            case .networkError:
+            case .networkError, .requestTimeout:
                 return true
             default:
                 return false
@@ -100,7 +108,12 @@ public struct RetryExecutor {
     }
 
     /// Execute an async operation with retries
+    ///
+    /// - Parameters:
+    ///   - onRetry: Called with the failed attempt number (0-indexed) and its error before each retry
+    ///   - operation: The operation to run
     public func execute<T>(
+        onRetry: ((_ attempt: Int, _ error: Error) -> Void)? = nil,
         operation: @Sendable () async throws -> T
     ) async throws -> T {
         var lastError: Error?
@@ -116,6 +129,7 @@ public struct RetryExecutor {
                 }
 
                 if attempt < policy.maxRetries {
+                    onRetry?(attempt, error)
                     let delay = policy.delayForAttempt(attempt)
                     try await Task.sleep(nanoseconds: UInt64(delay * 1_000_000_000))
                 }
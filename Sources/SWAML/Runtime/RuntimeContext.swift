@@ -5,7 +5,7 @@ public struct RuntimeContext: Sendable {
     /// Tags for this execution (for logging/tracing)
     public let tags: [String: String]
 
-    /// Client name override (uses default if nil)
+    /// Client override: a registered name or a "provider/model" reference (uses default if nil)
     public let clientName: String?
 
     /// Temperature override
@@ -20,9 +20,27 @@ public struct RuntimeContext: Sendable {
     /// Custom headers to include in requests
     public let customHeaders: [String: String]
 
-    /// Timeout for the request (in seconds)
+    /// Total deadline for each request attempt (in seconds).
+    /// Overrides the client's `HTTPConfig.totalTimeout`.
     public let timeout: TimeInterval?
 
+    /// How strictly the response is coerced to the output schema
+    public let coercion: CoercionOptions
+
+    /// How to locate JSON in the response
+    public let extraction: ExtractionStrategy
+
+    /// Fail before sending when the prompt plus `maxTokens` exceeds the model's context window
+    public let checkContextWindow: Bool
+
+    /// Clients (names or "provider/model" references) to race. With two or more, each call
+    /// is sent to all of them and the first response that parses wins; `clientName` is ignored.
+    public let raceClients: [String]
+
+    /// How many times to re-ask the model, with the error, when its response fails to parse
+    /// or validate
+    public let validationRetries: Int
+
     public init(
         tags: [String: String] = [:],
         clientName: String? = nil,
@@ -30,7 +48,12 @@ public struct RuntimeContext: Sendable {
         maxTokens: Int? = nil,
         responseFormat: ResponseFormat? = nil,
         customHeaders: [String: String] = [:],
-        timeout: TimeInterval? = nil
+        timeout: TimeInterval? = nil,
+        coercion: CoercionOptions = .default,
+        extraction: ExtractionStrategy = .preferFenced,
+        checkContextWindow: Bool = false,
+        raceClients: [String] = [],
+        validationRetries: Int = 0
     ) {
         self.tags = tags
         self.clientName = clientName
@@ -39,6 +62,11 @@ public struct RuntimeContext: Sendable {
         self.responseFormat = responseFormat
         self.customHeaders = customHeaders
         self.timeout = timeout
+        self.coercion = coercion
+        self.extraction = extraction
+        self.checkContextWindow = checkContextWindow
+        self.raceClients = raceClients
+        self.validationRetries = validationRetries
     }
 
     /// Create a child context with merged settings
@@ -57,7 +85,12 @@ public struct RuntimeContext: Sendable {
             maxTokens: maxTokens ?? self.maxTokens,
             responseFormat: responseFormat ?? self.responseFormat,
             customHeaders: self.customHeaders.merging(customHeaders) { _, new in new },
-            timeout: self.timeout
+            timeout: self.timeout,
+            coercion: self.coercion,
+            extraction: self.extraction,
+            checkContextWindow: self.checkContextWindow,
+            raceClients: self.raceClients,
+            validationRetries: self.validationRetries
         )
     }
 
@@ -76,6 +109,11 @@ public class RuntimeContextBuilder {
     private var responseFormat: ResponseFormat?
     private var customHeaders: [String: String] = [:]
     private var timeout: TimeInterval?
+    private var coercion: CoercionOptions = .default
+    private var extraction: ExtractionStrategy = .preferFenced
+    private var checkContextWindow = false
+    private var raceClients: [String] = []
+    private var validationRetries = 0
 
     public init() {}
 
@@ -127,6 +165,38 @@ public class RuntimeContextBuilder {
         return self
     }
 
+    @discardableResult
+    public func coercion(_ options: CoercionOptions) -> RuntimeContextBuilder {
+        coercion = options
+        return self
+    }
+
+    @discardableResult
+    public func extraction(_ strategy: ExtractionStrategy) -> RuntimeContextBuilder {
+        extraction = strategy
+        return self
+    }
+
+    @discardableResult
+    public func checkContextWindow(_ enabled: Bool = true) -> RuntimeContextBuilder {
+        checkContextWindow = enabled
+        return self
+    }
+
+    /// Send each call to all of these clients and keep the first response that parses
+    @discardableResult
+    public func race(_ clients: [String]) -> RuntimeContextBuilder {
+        raceClients = clients
+        return self
+    }
+
+    /// Re-ask the model up to `count` times when its response fails to parse or validate
+    @discardableResult
+    public func validationRetries(_ count: Int) -> RuntimeContextBuilder {
+        validationRetries = count
+        return self
+    }
+
     public func build() -> RuntimeContext {
         RuntimeContext(
             tags: tags,
@@ -135,7 +205,12 @@ public class RuntimeContextBuilder {
             maxTokens: maxTokens,
             responseFormat: responseFormat,
             customHeaders: customHeaders,
-            timeout: timeout
+            timeout: timeout,
+            coercion: coercion,
+            extraction: extraction,
+            checkContextWindow: checkContextWindow,
+            raceClients: raceClients,
+            validationRetries: validationRetries
         )
     }
 }
@@ -144,10 +219,17 @@ public class RuntimeContextBuilder {
 
 extension RuntimeContext {
     /// Create a context with a specific client
+    ///
+    /// - Parameter name: A registered client name, or "provider/model" (e.g. "openai/gpt-4o-mini")
     public static func withClient(_ name: String) -> RuntimeContext {
         RuntimeContext(clientName: name)
     }
 
+    /// A copy of this context that calls a different client or model
+    public func withClient(_ name: String) -> RuntimeContext {
+        child(clientName: name)
+    }
+
     /// Create a context with tags
     public static func withTags(_ tags: [String: String]) -> RuntimeContext {
         RuntimeContext(tags: tags)
@@ -0,0 +1,367 @@
+import Foundation
+
+/// Broad classes of function call failures, used as a metrics label
+public enum FailureCategory: String, Sendable, CaseIterable {
+    case network
+    case timeout
+    case rateLimited = "rate_limited"
+    case api
+    case contextWindow = "context_window"
+    case parse
+    case validation
+    case configuration
+    /// The caller's task was cancelled; kept apart from timeouts so they stay a useful signal
+    case cancelled
+    case other
+
+    /// Classify an error thrown by a function call
+    public init(error: Error) {
+        guard let swamlError = error as? SwamlError else {
+            if error is CancellationError {
+                self = .cancelled
+            } else if let urlError = error as? URLError {
+                // Transport errors reach here unwrapped from URLSession
+                switch urlError.code {
+                case .cancelled:
+                    self = .cancelled
+                case .timedOut:
+                    self = .timeout
+                default:
+                    self = .network
+                }
+            } else {
+                self = .other
+            }
+            return
+        }
+
+        switch swamlError {
+        case .networkError:
+            self = .network
+        case .requestTimeout:
+            self = .timeout
+        case .apiError(let statusCode, _):
+            self = statusCode == 429 ? .rateLimited : .api
+        case .contextWindowExceeded:
+            self = .contextWindow
+        case .parseError, .jsonExtractionError:
+            self = .parse
+        case .typeCoercionError, .schemaValidationError:
+            self = .validation
+        case .clientNotFound, .configurationError, .invalidFunctionCall, .invalidMedia:
+            self = .configuration
+        default:
+            self = .other
+        }
+    }
+}
+
+/// Counters and latency histograms for function calls made through a runtime.
+///
+/// Every `SwamlRuntime` records into its `metrics`. Query the values directly, or render
+/// them in the Prometheus text exposition format with `prometheusText()` and serve that
+/// from your own `/metrics` handler.
+public final class RuntimeMetrics: @unchecked Sendable {
+    /// Upper bounds (in seconds) of the call latency histogram buckets
+    public static let latencyBuckets: [TimeInterval] = [0.1, 0.25, 0.5, 1, 2.5, 5, 10, 30, 60, 120]
+
+    private struct Histogram {
+        var bucketCounts = [Int](repeating: 0, count: RuntimeMetrics.latencyBuckets.count)
+        var sum: TimeInterval = 0
+        var count = 0
+
+        mutating func observe(_ value: TimeInterval) {
+            for (index, bound) in RuntimeMetrics.latencyBuckets.enumerated() where value <= bound {
+                bucketCounts[index] += 1
+            }
+            sum += value
+            count += 1
+        }
+    }
+
+    private struct TokenCounts {
+        var prompt = 0
+        var completion = 0
+    }
+
+    private let lock = NSLock()
+    private var successes: [String: Int] = [:]
+    private var failures: [String: [FailureCategory: Int]] = [:]
+    private var retries: [String: Int] = [:]
+    private var validationRetries: [String: Int] = [:]
+    private var latencies: [String: Histogram] = [:]
+    private var tokens: [String: TokenCounts] = [:]
+    private var queueWaits: [String: (count: Int, seconds: TimeInterval)] = [:]
+    private var raceWins: [String: [String: Int]] = [:]
+
+    public init() {}
+
+    // MARK: - Recording
+
+    /// Record a finished function call
+    func recordCall(function: String, duration: TimeInterval, error: Error?) {
+        lock.lock()
+        defer { lock.unlock() }
+
+        if let error = error {
+            failures[function, default: [:]][FailureCategory(error: error), default: 0] += 1
+        } else {
+            successes[function, default: 0] += 1
+        }
+        latencies[function, default: Histogram()].observe(duration)
+    }
+
+    /// Record a retried attempt
+    func recordRetry(function: String) {
+        lock.lock()
+        defer { lock.unlock() }
+        retries[function, default: 0] += 1
+    }
+
+    /// Record a re-ask after a response failed to parse or validate
+    func recordValidationRetry(function: String) {
+        lock.lock()
+        defer { lock.unlock() }
+        validationRetries[function, default: 0] += 1
+    }
+
+    /// Record a call that waited for a concurrency slot
+    func recordQueueWait(function: String, duration: TimeInterval) {
+        lock.lock()
+        defer { lock.unlock() }
+        queueWaits[function, default: (0, 0)].count += 1
+        queueWaits[function, default: (0, 0)].seconds += duration
+    }
+
+    /// Record the client that won a race
+    func recordRaceWin(function: String, client: String) {
+        lock.lock()
+        defer { lock.unlock() }
+        raceWins[function, default: [:]][client, default: 0] += 1
+    }
+
+    /// Record token usage reported by the provider
+    func recordUsage(model: String, usage: LLMResponse.Usage) {
+        lock.lock()
+        defer { lock.unlock() }
+        tokens[model, default: TokenCounts()].prompt += usage.promptTokens
+        tokens[model, default: TokenCounts()].completion += usage.completionTokens
+    }
+
+    // MARK: - Queries
+
+    /// Number of calls to a function, or to all functions when nil
+    public func callCount(function: String? = nil) -> Int {
+        lock.lock()
+        defer { lock.unlock() }
+        return latencies
+            .filter { function == nil || $0.key == function }
+            .reduce(0) { $0 + $1.value.count }
+    }
+
+    /// Number of failed calls, optionally filtered by function and category
+    public func failureCount(function: String? = nil, category: FailureCategory? = nil) -> Int {
+        lock.lock()
+        defer { lock.unlock() }
+        return failures
+            .filter { function == nil || $0.key == function }
+            .flatMap { $0.value }
+            .filter { category == nil || $0.key == category }
+            .reduce(0) { $0 + $1.value }
+    }
+
+    /// Number of retried attempts, optionally for one function
+    public func retryCount(function: String? = nil) -> Int {
+        lock.lock()
+        defer { lock.unlock() }
+        return retries
+            .filter { function == nil || $0.key == function }
+            .reduce(0) { $0 + $1.value }
+    }
+
+    /// Number of re-asks after invalid responses, optionally for one function
+    public func validationRetryCount(function: String? = nil) -> Int {
+        lock.lock()
+        defer { lock.unlock() }
+        return validationRetries
+            .filter { function == nil || $0.key == function }
+            .reduce(0) { $0 + $1.value }
+    }
+
+    /// Prompt tokens reported by the provider, optionally for one model
+    public func promptTokens(model: String? = nil) -> Int {
+        lock.lock()
+        defer { lock.unlock() }
+        return tokens
+            .filter { model == nil || $0.key == model }
+            .reduce(0) { $0 + $1.value.prompt }
+    }
+
+    /// Completion tokens reported by the provider, optionally for one model
+    public func completionTokens(model: String? = nil) -> Int {
+        lock.lock()
+        defer { lock.unlock() }
+        return tokens
+            .filter { model == nil || $0.key == model }
+            .reduce(0) { $0 + $1.value.completion }
+    }
+
+    /// Total call latency (in seconds) for a function
+    public func totalLatency(function: String) -> TimeInterval {
+        lock.lock()
+        defer { lock.unlock() }
+        return latencies[function]?.sum ?? 0
+    }
+
+    /// Number of calls that waited for a concurrency slot, optionally for one function
+    public func queuedCallCount(function: String? = nil) -> Int {
+        lock.lock()
+        defer { lock.unlock() }
+        return queueWaits
+            .filter { function == nil || $0.key == function }
+            .reduce(0) { $0 + $1.value.count }
+    }
+
+    /// Total time (in seconds) calls to a function spent waiting for a concurrency slot
+    public func totalQueueWait(function: String) -> TimeInterval {
+        lock.lock()
+        defer { lock.unlock() }
+        return queueWaits[function]?.seconds ?? 0
+    }
+
+    /// Number of races a client won, optionally for one function
+    public func raceWinCount(client: String, function: String? = nil) -> Int {
+        lock.lock()
+        defer { lock.unlock() }
+        return raceWins
+            .filter { function == nil || $0.key == function }
+            .reduce(0) { $0 + ($1.value[client] ?? 0) }
+    }
+
+    /// Clear all recorded values
+    public func reset() {
+        lock.lock()
+        defer { lock.unlock() }
+        successes = [:]
+        failures = [:]
+        retries = [:]
+        validationRetries = [:]
+        latencies = [:]
+        tokens = [:]
+        queueWaits = [:]
+        raceWins = [:]
+    }
+
+    // MARK: - Prometheus Export
+
+    /// All metrics in the Prometheus text exposition format (version 0.0.4)
+    public func prometheusText() -> String {
+        lock.lock()
+        defer { lock.unlock() }
+
+        var lines: [String] = []
+
+        lines.append("# HELP swaml_calls_total Function calls by outcome.")
+        lines.append("# TYPE swaml_calls_total counter")
+        for function in latencies.keys.sorted() {
+            let failed = failures[function]?.values.reduce(0, +) ?? 0
+            lines.append(sample("swaml_calls_total", [("function", function), ("outcome", "success")], successes[function] ?? 0))
+            lines.append(sample("swaml_calls_total", [("function", function), ("outcome", "failure")], failed))
+        }
+
+        lines.append("# HELP swaml_failures_total Failed function calls by category.")
+        lines.append("# TYPE swaml_failures_total counter")
+        for function in failures.keys.sorted() {
+            let byCategory = failures[function] ?? [:]
+            for category in byCategory.keys.sorted(by: { $0.rawValue < $1.rawValue }) {
+                lines.append(sample(
+                    "swaml_failures_total",
+                    [("category", category.rawValue), ("function", function)],
+                    byCategory[category] ?? 0
+                ))
+            }
+        }
+
+        lines.append("# HELP swaml_retries_total Retried LLM requests.")
+        lines.append("# TYPE swaml_retries_total counter")
+        for function in retries.keys.sorted() {
+            lines.append(sample("swaml_retries_total", [("function", function)], retries[function] ?? 0))
+        }
+
+        lines.append("# HELP swaml_validation_retries_total Re-asks after a response failed to parse or validate.")
+        lines.append("# TYPE swaml_validation_retries_total counter")
+        for function in validationRetries.keys.sorted() {
+            lines.append(sample("swaml_validation_retries_total", [("function", function)], validationRetries[function] ?? 0))
+        }
+
+        lines.append("# HELP swaml_call_duration_seconds Function call latency, including retries.")
+        lines.append("# TYPE swaml_call_duration_seconds histogram")
+        for function in latencies.keys.sorted() {
+            guard let histogram = latencies[function] else { continue }
+            for (index, bound) in Self.latencyBuckets.enumerated() {
+                lines.append(sample(
+                    "swaml_call_duration_seconds_bucket",
+                    [("function", function), ("le", formatNumber(bound))],
+                    histogram.bucketCounts[index]
+                ))
+            }
+            lines.append(sample("swaml_call_duration_seconds_bucket", [("function", function), ("le", "+Inf")], histogram.count))
+            lines.append("swaml_call_duration_seconds_sum\(labels([("function", function)])) \(formatNumber(histogram.sum))")
+            lines.append(sample("swaml_call_duration_seconds_count", [("function", function)], histogram.count))
+        }
+
+        lines.append("# HELP swaml_queued_calls_total Calls that waited for a concurrency slot.")
+        lines.append("# TYPE swaml_queued_calls_total counter")
+        for function in queueWaits.keys.sorted() {
+            lines.append(sample("swaml_queued_calls_total", [("function", function)], queueWaits[function]?.count ?? 0))
+        }
+
+        lines.append("# HELP swaml_queue_wait_seconds_total Time spent waiting for a concurrency slot.")
+        lines.append("# TYPE swaml_queue_wait_seconds_total counter")
+        for function in queueWaits.keys.sorted() {
+            let seconds = queueWaits[function]?.seconds ?? 0
+            lines.append("swaml_queue_wait_seconds_total\(labels([("function", function)])) \(formatNumber(seconds))")
+        }
+
+        lines.append("# HELP swaml_race_wins_total Races won by each client.")
+        lines.append("# TYPE swaml_race_wins_total counter")
+        for function in raceWins.keys.sorted() {
+            let byClient = raceWins[function] ?? [:]
+            for client in byClient.keys.sorted() {
+                lines.append(sample("swaml_race_wins_total", [("client", client), ("function", function)], byClient[client] ?? 0))
+            }
+        }
+
+        lines.append("# HELP swaml_tokens_total Tokens reported by providers.")
+        lines.append("# TYPE swaml_tokens_total counter")
+        for model in tokens.keys.sorted() {
+            guard let counts = tokens[model] else { continue }
+            lines.append(sample("swaml_tokens_total", [("model", model), ("type", "completion")], counts.completion))
+            lines.append(sample("swaml_tokens_total", [("model", model), ("type", "prompt")], counts.prompt))
+        }
+
+        return lines.joined(separator: "\n") + "\n"
+    }
+
+    // MARK: - Formatting
+
+    private func sample(_ name: String, _ labelPairs: [(String, String)], _ value: Int) -> String {
+        "\(name)\(labels(labelPairs)) \(value)"
+    }
+
+    private func labels(_ pairs: [(String, String)]) -> String {
+        let rendered = pairs.map { "\($0.0)=\"\(escapeLabel($0.1))\"" }
+        return "{" + rendered.joined(separator: ",") + "}"
+    }
+
+    private func escapeLabel(_ value: String) -> String {
+        value
+            .replacingOccurrences(of: "\\", with: "\\\\")
+            .replacingOccurrences(of: "\"", with: "\\\"")
+            .replacingOccurrences(of: "\n", with: "\\n")
+    }
+
+    private func formatNumber(_ value: Double) -> String {
+        value == value.rounded() && abs(value) < 1e15 ? String(Int(value)) : String(value)
+    }
+}
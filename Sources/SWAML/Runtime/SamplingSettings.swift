@@ -0,0 +1,50 @@
+import Foundation
+
+/// Forces reproducible sampling for every call made through a runtime.
+///
+/// Temperature is set to 0 and the seed is sent to providers that accept one
+/// (OpenAI-compatible APIs). Providers don't guarantee identical output even then, but
+/// reruns are as stable as they allow.
+public struct DeterministicMode: Sendable, Equatable {
+    /// Seed sent with each request
+    public let seed: Int
+
+    public init(seed: Int = 0) {
+        self.seed = seed
+    }
+}
+
+/// The sampling parameters actually sent for a request
+public struct SamplingSettings: Sendable, Equatable {
+    /// Name of the client that handled the request
+    public let client: String
+    public let model: String
+    public let temperature: Double?
+    public let maxTokens: Int?
+    /// Seed sent to the provider, or nil if none was sent
+    public let seed: Int?
+
+    public init(client: String, model: String, temperature: Double?, maxTokens: Int?, seed: Int?) {
+        self.client = client
+        self.model = model
+        self.temperature = temperature
+        self.maxTokens = maxTokens
+        self.seed = seed
+    }
+
+    /// Resolve request settings from call overrides, client defaults and the deterministic mode
+    static func resolve(
+        config: ClientConfig,
+        temperature: Double?,
+        maxTokens: Int?,
+        deterministic: DeterministicMode?
+    ) -> SamplingSettings {
+        SamplingSettings(
+            client: config.name,
+            model: config.model,
+            temperature: deterministic != nil ? 0 : temperature ?? config.defaultTemperature,
+            maxTokens: maxTokens ?? config.defaultMaxTokens,
+            seed: config.provider.supportsSeed ? deterministic?.seed : nil
+        )
+    }
+}
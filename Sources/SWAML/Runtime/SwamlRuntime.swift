@@ -5,12 +5,59 @@ public actor SwamlRuntime {
     public let clientRegistry: ClientRegistry
     public let defaultRetryPolicy: RetryPolicy
 
+    /// Call counts, failures, latency, retries and token usage
+    public let metrics: RuntimeMetrics
+
+    /// Per-function caps on concurrent calls
+    public let concurrencyLimiter: ConcurrencyLimiter
+
+    /// Output hooks, in the order they run
+    public private(set) var postProcessors: [OutputPostProcessor] = []
+
+    /// Forces temperature 0 and a fixed seed for every call when set
+    public private(set) var deterministicMode: DeterministicMode?
+
+    /// Sampling settings behind the most recent response used for each function
+    private var lastSampling: [String: SamplingSettings] = [:]
+
     public init(
         clientRegistry: ClientRegistry,
-        defaultRetryPolicy: RetryPolicy = .standard
+        defaultRetryPolicy: RetryPolicy = .standard,
+        metrics: RuntimeMetrics = RuntimeMetrics(),
+        concurrencyLimits: [String: Int] = [:],
+        deterministicMode: DeterministicMode? = nil
     ) {
         self.clientRegistry = clientRegistry
         self.defaultRetryPolicy = defaultRetryPolicy
+        self.metrics = metrics
+        self.concurrencyLimiter = ConcurrencyLimiter(limits: concurrencyLimits)
+        self.deterministicMode = deterministicMode
+    }
+
+    /// Turn deterministic sampling on (with a seed) or off (nil)
+    public func setDeterministicMode(_ mode: DeterministicMode?) {
+        deterministicMode = mode
+    }
+
+    /// The sampling settings sent for the most recent call to a function. When racing, these
+    /// are the winning client's settings.
+    public func samplingSettings(for function: String) -> SamplingSettings? {
+        lastSampling[function]
+    }
+
+    /// Limit how many calls to a function may run at once (nil removes the limit)
+    public func setConcurrencyLimit(_ limit: Int?, for function: String) async {
+        await concurrencyLimiter.setLimit(limit, for: function)
+    }
+
+    /// Add a hook that rewrites parsed output for matching functions
+    public func addPostProcessor(_ processor: OutputPostProcessor) {
+        postProcessors.append(processor)
+    }
+
+    /// Remove all post-processors with the given name
+    public func removePostProcessors(named name: String) {
+        postProcessors.removeAll { $0.name == name }
     }
 
     /// Call a SWAML function with the given arguments
@@ -18,104 +65,343 @@ public actor SwamlRuntime {
         _ name: String,
         args: [String: SwamlValue],
         prompt: String,
+        images: [ChatMessage.ContentPart] = [],
         outputSchema: JSONSchema? = nil,
         typeBuilder: TypeBuilder? = nil,
         ctx: RuntimeContext = .default
     ) async throws -> SwamlValue {
-        // Get the client configuration
-        let clientConfig: ClientConfig
-        if let clientName = ctx.clientName {
-            guard let config = await clientRegistry.getConfig(clientName) else {
-                throw SwamlError.clientNotFound(clientName)
+        let processors = matchingPostProcessors(for: name)
+
+        return try await execute(
+            name,
+            prompt: prompt,
+            images: images,
+            outputSchema: outputSchema,
+            typeBuilder: typeBuilder,
+            ctx: ctx,
+            parse: { content, schema in
+                try OutputParser.parseToValue(
+                    content,
+                    schema: schema,
+                    options: ctx.coercion,
+                    extraction: ctx.extraction
+                )
+            },
+            finish: { value in
+                try self.postProcess(value, with: processors)
             }
-            clientConfig = config
-        } else {
-            guard let config = await clientRegistry.getDefaultConfig() else {
-                throw SwamlError.configurationError("No default client configured")
+        )
+    }
+
+    /// Call a function with typed output
+    public func callFunction<T: Codable>(
+        _ name: String,
+        args: [String: SwamlValue],
+        prompt: String,
+        images: [ChatMessage.ContentPart] = [],
+        outputSchema: JSONSchema? = nil,
+        outputType: T.Type,
+        typeBuilder: TypeBuilder? = nil,
+        ctx: RuntimeContext = .default
+    ) async throws -> T {
+        let processors = matchingPostProcessors(for: name)
+
+        return try await execute(
+            name,
+            prompt: prompt,
+            images: images,
+            outputSchema: outputSchema,
+            typeBuilder: typeBuilder,
+            ctx: ctx,
+            parse: { content, schema in
+                // The same extraction, coercion and decoding as OutputParser.parse, keeping the
+                // value so post-processors don't change how the output is checked
+                let value = try OutputParser.coercedValue(
+                    content,
+                    schema: schema,
+                    options: ctx.coercion,
+                    extraction: ctx.extraction
+                )
+                let output = try OutputParser.decode(value, as: T.self)
+                return (value: value, output: output)
+            },
+            finish: { parsed in
+                guard !processors.isEmpty else {
+                    return parsed.output
+                }
+                let processed = try self.postProcess(parsed.value, with: processors)
+                do {
+                    return try OutputParser.decode(processed, as: T.self)
+                } catch {
+                    let names = processors.map(\.name).joined(separator: ", ")
+                    throw SwamlError.internalError("Output of post-processors [\(names)] no longer decodes as \(T.self): \(error.localizedDescription)")
+                }
             }
-            clientConfig = config
-        }
+        )
+    }
+
+    /// Execute a raw completion (no function abstraction)
+    public func complete(
+        messages: [ChatMessage],
+        clientName: String? = nil,
+        temperature: Double? = nil,
+        maxTokens: Int? = nil,
+        responseFormat: ResponseFormat? = nil,
+        timeout: TimeInterval? = nil
+    ) async throws -> LLMResponse {
+        // Get the client configuration
+        let clientConfig = try await resolveClientConfig(clientName)
 
         // Get the LLM client
         let client = try await clientRegistry.getClient(clientConfig.name)
 
-        // Build messages
-        let messages = [ChatMessage.user(prompt)]
-
-        // Merge TypeBuilder schemas with output schema
-        let finalSchema = mergeSchemaWithTypeBuilder(outputSchema, typeBuilder: typeBuilder)
-
-        // Determine response format - always use JSON when we have a schema or typed output
-        let responseFormat: ResponseFormat?
-        if let schema = finalSchema {
-            responseFormat = .jsonSchema(
-                name: name,
-                schema: schema.toDictionary(),
-                strict: true
-            )
-        } else if ctx.responseFormat != nil {
-            responseFormat = ctx.responseFormat
-        } else {
-            // Default to JSON object format for typed outputs
-            responseFormat = .jsonObject
-        }
+        let sampling = SamplingSettings.resolve(
+            config: clientConfig,
+            temperature: temperature,
+            maxTokens: maxTokens,
+            deterministic: deterministicMode
+        )
 
         // Execute with retry
         let retryExecutor = RetryExecutor(policy: clientConfig.retryPolicy)
 
-        let response = try await retryExecutor.execute {
+        return try await retryExecutor.execute {
             try await client.complete(
                 model: clientConfig.model,
                 messages: messages,
                 responseFormat: responseFormat,
-                temperature: ctx.temperature ?? clientConfig.defaultTemperature,
-                maxTokens: ctx.maxTokens ?? clientConfig.defaultMaxTokens
+                temperature: sampling.temperature,
+                maxTokens: sampling.maxTokens,
+                seed: sampling.seed,
+                timeout: timeout
             )
         }
+    }
+
+    // MARK: - Function Execution
+
+    /// Shared pipeline for function calls: wait for a concurrency slot, request (or race)
+    /// the completion, parse, post-process, and record metrics
+    ///
+    /// Only `parse` failures are fed back to the model; `finish` runs once on the accepted output.
+    private func execute<Parsed, Output>(
+        _ name: String,
+        prompt: String,
+        images: [ChatMessage.ContentPart],
+        outputSchema: JSONSchema?,
+        typeBuilder: TypeBuilder?,
+        ctx: RuntimeContext,
+        parse: (String, JSONSchema?) throws -> Parsed,
+        finish: (Parsed) throws -> Output
+    ) async throws -> Output {
+        let queuedAt = Date()
+        if try await concurrencyLimiter.acquire(name) {
+            metrics.recordQueueWait(function: name, duration: Date().timeIntervalSince(queuedAt))
+        }
 
-        // Parse the response
-        return try OutputParser.parseToValue(response.content, schema: finalSchema)
+        // Merge TypeBuilder schemas with output schema
+        let finalSchema = mergeSchemaWithTypeBuilder(outputSchema, typeBuilder: typeBuilder)
+
+        let start = Date()
+        do {
+            let parsed = try await requestAndParse(
+                name,
+                prompt: prompt,
+                images: images,
+                schema: finalSchema,
+                typeBuilder: typeBuilder,
+                ctx: ctx,
+                parse: parse
+            )
+            let output = try finish(parsed)
+            await concurrencyLimiter.release(name)
+            metrics.recordCall(function: name, duration: Date().timeIntervalSince(start), error: nil)
+            return output
+        } catch {
+            await concurrencyLimiter.release(name)
+            metrics.recordCall(function: name, duration: Date().timeIntervalSince(start), error: error)
+            throw error
+        }
     }
 
-    /// Call a function with typed output
-    public func callFunction<T: Codable>(
+    /// Request a completion and parse it, asking the model to correct output that fails to
+    /// parse or validate up to `ctx.validationRetries` times
+    private func requestAndParse<Output>(
         _ name: String,
-        args: [String: SwamlValue],
         prompt: String,
-        outputSchema: JSONSchema? = nil,
-        outputType: T.Type,
-        typeBuilder: TypeBuilder? = nil,
-        ctx: RuntimeContext = .default
-    ) async throws -> T {
-        // Get the client configuration
-        let clientConfig: ClientConfig
-        if let clientName = ctx.clientName {
-            guard let config = await clientRegistry.getConfig(clientName) else {
-                throw SwamlError.clientNotFound(clientName)
+        images: [ChatMessage.ContentPart],
+        schema: JSONSchema?,
+        typeBuilder: TypeBuilder?,
+        ctx: RuntimeContext,
+        parse: (String, JSONSchema?) throws -> Output
+    ) async throws -> Output {
+        // Earlier invalid responses and the feedback sent about them
+        var feedback: [ChatMessage] = []
+        var validationAttempt = 0
+
+        while true {
+            do {
+                if ctx.raceClients.count > 1 {
+                    return try await race(
+                        name,
+                        clients: ctx.raceClients,
+                        prompt: prompt,
+                        images: images,
+                        feedback: feedback,
+                        schema: schema,
+                        typeBuilder: typeBuilder,
+                        ctx: ctx,
+                        parse: parse
+                    )
+                }
+
+                let response = try await request(
+                    name,
+                    clientName: ctx.raceClients.first ?? ctx.clientName,
+                    prompt: prompt,
+                    images: images,
+                    feedback: feedback,
+                    schema: schema,
+                    typeBuilder: typeBuilder,
+                    ctx: ctx
+                )
+                lastSampling[name] = response.sampling
+                return try parseResponse(response.content, schema: schema, parse: parse)
+            } catch let rejected as RejectedOutput {
+                guard validationAttempt < ctx.validationRetries else {
+                    throw rejected.error
+                }
+                validationAttempt += 1
+                metrics.recordValidationRetry(function: name)
+                feedback += [.assistant(rejected.content), .user(validationFeedback(for: rejected.error))]
             }
-            clientConfig = config
-        } else {
-            guard let config = await clientRegistry.getDefaultConfig() else {
-                throw SwamlError.configurationError("No default client configured")
+        }
+    }
+
+    /// Parse a response, marking output failures so the caller can ask the model again
+    private nonisolated func parseResponse<Output>(
+        _ content: String,
+        schema: JSONSchema?,
+        parse: (String, JSONSchema?) throws -> Output
+    ) throws -> Output {
+        do {
+            return try parse(content, schema)
+        } catch let error as SwamlError where isOutputFailure(error) {
+            throw RejectedOutput(content: content, error: error)
+        }
+    }
+
+    /// Send the same request to several clients and return the first response that `parse`
+    /// accepts, already parsed. The remaining requests are cancelled.
+    private func race<Output>(
+        _ name: String,
+        clients: [String],
+        prompt: String,
+        images: [ChatMessage.ContentPart],
+        feedback: [ChatMessage],
+        schema: JSONSchema?,
+        typeBuilder: TypeBuilder?,
+        ctx: RuntimeContext,
+        parse: (String, JSONSchema?) throws -> Output
+    ) async throws -> Output {
+        try await withThrowingTaskGroup(of: (client: String, content: String, sampling: SamplingSettings).self) { group in
+            for client in clients {
+                group.addTask {
+                    let response = try await self.request(
+                        name,
+                        clientName: client,
+                        prompt: prompt,
+                        images: images,
+                        feedback: feedback,
+                        schema: schema,
+                        typeBuilder: typeBuilder,
+                        ctx: ctx
+                    )
+                    return (client, response.content, response.sampling)
+                }
             }
-            clientConfig = config
+
+            // Responses are parsed as they arrive, with the caller's own parser, so the
+            // winner is a response the caller can actually use
+            var lastError: Error?
+            var lastRejected: RejectedOutput?
+            while let result = await group.nextResult() {
+                do {
+                    let response = try result.get()
+                    let output = try parseResponse(response.content, schema: schema, parse: parse)
+                    group.cancelAll()
+                    metrics.recordRaceWin(function: name, client: response.client)
+                    lastSampling[name] = response.sampling
+                    return output
+                } catch let rejected as RejectedOutput {
+                    lastRejected = rejected
+                } catch {
+                    lastError = error
+                }
+            }
+            // Prefer a response that arrived but was unusable, so validation retries can
+            // send it back for correction
+            if let rejected = lastRejected {
+                throw rejected
+            }
+            throw lastError ?? SwamlError.configurationError("No clients to race")
         }
+    }
+
+    /// Resolve the client, build the messages, and send the request with retry
+    ///
+    /// Returns the content with the sampling settings that were sent, so the caller can record
+    /// them for the response it keeps.
+    private func request(
+        _ name: String,
+        clientName: String?,
+        prompt: String,
+        images: [ChatMessage.ContentPart],
+        feedback: [ChatMessage],
+        schema finalSchema: JSONSchema?,
+        typeBuilder: TypeBuilder?,
+        ctx: RuntimeContext
+    ) async throws -> (content: String, sampling: SamplingSettings) {
+        // Get the client configuration
+        let clientConfig = try await resolveClientConfig(clientName)
 
         // Get the LLM client
         let client = try await clientRegistry.getClient(clientConfig.name)
 
-        // Build messages
-        let messages = [ChatMessage.user(prompt)]
+        if !images.isEmpty, ModelCapabilities.supportsImages(clientConfig.model) == false {
+            throw SwamlError.invalidFunctionCall(
+                name: name,
+                reason: "client '\(clientConfig.name)' uses \(clientConfig.model), which does not accept image input"
+            )
+        }
 
-        // Merge TypeBuilder schemas with output schema
-        let finalSchema = mergeSchemaWithTypeBuilder(outputSchema, typeBuilder: typeBuilder)
+        // Build messages, attaching any images after the prompt text
+        let promptMessage = images.isEmpty
+            ? ChatMessage.user(prompt)
+            : ChatMessage(role: .user, content: .multipart([.text(prompt)] + images))
+        let messages = [promptMessage] + feedback
+
+        let sampling = SamplingSettings.resolve(
+            config: clientConfig,
+            temperature: ctx.temperature,
+            maxTokens: ctx.maxTokens,
+            deterministic: deterministicMode
+        )
+
+        if ctx.checkContextWindow {
+            try preflightContextWindow(
+                messages,
+                config: clientConfig,
+                maxTokens: sampling.maxTokens
+            )
+        }
 
         // Determine response format - always use JSON when we have a schema or typed output
         let responseFormat: ResponseFormat?
         if let schema = finalSchema {
             responseFormat = .jsonSchema(
                 name: name,
-                schema: schema.toDictionary(),
+                schema: schemaDocument(schema, typeBuilder: typeBuilder),
                 strict: true
             )
         } else if ctx.responseFormat != nil {
@@ -127,64 +413,178 @@ public actor SwamlRuntime {
 
         // Execute with retry
         let retryExecutor = RetryExecutor(policy: clientConfig.retryPolicy)
+        let metrics = self.metrics
 
-        let response = try await retryExecutor.execute {
+        let response = try await retryExecutor.execute(onRetry: { _, _ in
+            metrics.recordRetry(function: name)
+        }) {
             try await client.complete(
                 model: clientConfig.model,
                 messages: messages,
                 responseFormat: responseFormat,
-                temperature: ctx.temperature ?? clientConfig.defaultTemperature,
-                maxTokens: ctx.maxTokens ?? clientConfig.defaultMaxTokens
+                temperature: sampling.temperature,
+                maxTokens: sampling.maxTokens,
+                seed: sampling.seed,
+                timeout: ctx.timeout
             )
         }
 
-        // Parse the response
-        return try OutputParser.parse(response.content, schema: finalSchema, type: T.self)
+        if let usage = response.usage {
+            metrics.recordUsage(model: clientConfig.model, usage: usage)
+        }
+
+        return (response.content, sampling)
     }
 
-    /// Execute a raw completion (no function abstraction)
-    public func complete(
-        messages: [ChatMessage],
-        clientName: String? = nil,
-        temperature: Double? = nil,
-        maxTokens: Int? = nil,
-        responseFormat: ResponseFormat? = nil
-    ) async throws -> LLMResponse {
-        // Get the client configuration
-        let clientConfig: ClientConfig
-        if let name = clientName {
-            guard let config = await clientRegistry.getConfig(name) else {
-                throw SwamlError.clientNotFound(name)
-            }
-            clientConfig = config
-        } else {
-            guard let config = await clientRegistry.getDefaultConfig() else {
-                throw SwamlError.configurationError("No default client configured")
+    /// A response that arrived but failed to parse or validate
+    private struct RejectedOutput: Error {
+        let content: String
+        let error: SwamlError
+    }
+
+    /// Whether an error means the response arrived but could not be used
+    private nonisolated func isOutputFailure(_ error: SwamlError) -> Bool {
+        switch error {
+        case .parseError, .jsonExtractionError, .typeCoercionError, .schemaValidationError:
+            return true
+        default:
+            return false
+        }
+    }
+
+    /// Follow-up message telling the model why its previous response was rejected
+    private nonisolated func validationFeedback(for error: SwamlError) -> String {
+        """
+        Your previous response could not be used: \(error.localizedDescription)
+        Respond again with only the corrected output, matching the required schema.
+        """
+    }
+
+    /// Post-processors whose pattern matches a function
+    private func matchingPostProcessors(for function: String) -> [OutputPostProcessor] {
+        postProcessors.filter { $0.matches(function) }
+    }
+
+    /// Run post-processors in order, naming the failing one in the error
+    ///
+    /// Every failure becomes an internal error, so it is never mistaken for bad model output.
+    private nonisolated func postProcess(_ value: SwamlValue, with processors: [OutputPostProcessor]) throws -> SwamlValue {
+        var value = value
+        for processor in processors {
+            do {
+                value = try processor.apply(to: value)
+            } catch {
+                throw SwamlError.internalError("Post-processor '\(processor.name)' failed: \(error.localizedDescription)")
             }
-            clientConfig = config
         }
+        return value
+    }
 
-        // Get the LLM client
-        let client = try await clientRegistry.getClient(clientConfig.name)
+    /// Look up a client by name or "provider/model" reference, or the default client
+    private func resolveClientConfig(_ clientName: String?) async throws -> ClientConfig {
+        if let clientName = clientName {
+            guard let config = await clientRegistry.resolve(clientName) else {
+                throw SwamlError.clientNotFound(clientName)
+            }
+            return config
+        }
 
-        // Execute with retry
-        let retryExecutor = RetryExecutor(policy: clientConfig.retryPolicy)
+        guard let config = await clientRegistry.getDefaultConfig() else {
+            throw SwamlError.configurationError("No default client configured")
+        }
+        return config
+    }
 
-        return try await retryExecutor.execute {
-            try await client.complete(
-                model: clientConfig.model,
-                messages: messages,
-                responseFormat: responseFormat,
-                temperature: temperature ?? clientConfig.defaultTemperature,
-                maxTokens: maxTokens ?? clientConfig.defaultMaxTokens
+    // MARK: - Orchestration Plan
+
+    /// Describe how a call to a function would be dispatched, without sending it
+    ///
+    /// - Throws: SwamlError.clientNotFound or configurationError if a client cannot be resolved
+    public func orchestrationPlan(for function: String, ctx: RuntimeContext = .default) async throws -> OrchestrationPlan {
+        let references: [String?] = ctx.raceClients.count > 1
+            ? ctx.raceClients
+            : [ctx.raceClients.first ?? ctx.clientName]
+
+        var nodes: [OrchestrationPlan.Node] = []
+        for reference in references {
+            let config = try await resolveClientConfig(reference)
+            let sampling = SamplingSettings.resolve(
+                config: config,
+                temperature: ctx.temperature,
+                maxTokens: ctx.maxTokens,
+                deterministic: deterministicMode
             )
+            nodes.append(OrchestrationPlan.Node(config: config, sampling: sampling, timeout: ctx.timeout))
+        }
+
+        return OrchestrationPlan(
+            function: function,
+            strategy: nodes.count > 1 ? .race : .single,
+            nodes: nodes,
+            validationRetries: ctx.validationRetries,
+            concurrencyLimit: await concurrencyLimiter.limit(for: function),
+            postProcessors: matchingPostProcessors(for: function).map(\.name)
+        )
+    }
+
+    // MARK: - Token Counting
+
+    /// Estimate the prompt tokens a function call would send to a client's model
+    public func countPromptTokens(_ prompt: String, clientName: String? = nil) async throws -> Int {
+        let config = try await resolveClientConfig(clientName)
+        return TokenCounter(model: config.model).count([ChatMessage.user(prompt)])
+    }
+
+    /// Throw if the messages plus the output budget exceed the model's context window.
+    /// Models with an unknown window are not checked.
+    private nonisolated func preflightContextWindow(
+        _ messages: [ChatMessage],
+        config: ClientConfig,
+        maxTokens: Int?
+    ) throws {
+        guard let limit = config.effectiveContextWindow else {
+            return
+        }
+
+        let tokens = TokenCounter(model: config.model).count(messages) + (maxTokens ?? 0)
+        if tokens > limit {
+            throw SwamlError.contextWindowExceeded(tokens: tokens, limit: limit)
+        }
+    }
+
+    // MARK: - Schema Export
+
+    /// JSON Schema document for a function's output, as sent to the provider
+    ///
+    /// Applies dynamic enum values from the TypeBuilder and adds `$defs` for any dynamic
+    /// classes or enums the schema references. Returns nil when there is no output schema.
+    public nonisolated func outputJSONSchema(
+        _ outputSchema: JSONSchema?,
+        typeBuilder: TypeBuilder? = nil
+    ) -> [String: Any]? {
+        guard let schema = mergeSchemaWithTypeBuilder(outputSchema, typeBuilder: typeBuilder) else {
+            return nil
         }
+        return schemaDocument(schema, typeBuilder: typeBuilder)
+    }
+
+    /// JSON Schema document for a typed function output
+    public nonisolated func outputJSONSchema<T: SwamlTyped>(
+        for type: T.Type,
+        typeBuilder: TypeBuilder? = nil
+    ) -> [String: Any] {
+        outputJSONSchema(T.swamlSchema, typeBuilder: typeBuilder) ?? T.swamlSchema.toDictionary()
     }
 
     // MARK: - Private Helpers
 
+    /// Serialize a schema, including definitions for TypeBuilder types it references
+    private nonisolated func schemaDocument(_ schema: JSONSchema, typeBuilder: TypeBuilder?) -> [String: Any] {
+        typeBuilder?.jsonSchemaDocument(for: schema) ?? schema.toDictionary()
+    }
+
     /// Merge TypeBuilder's dynamic enum values into the output schema
-    private func mergeSchemaWithTypeBuilder(_ schema: JSONSchema?, typeBuilder: TypeBuilder?) -> JSONSchema? {
+    private nonisolated func mergeSchemaWithTypeBuilder(_ schema: JSONSchema?, typeBuilder: TypeBuilder?) -> JSONSchema? {
         guard let schema = schema, let tb = typeBuilder else {
             return schema
         }
@@ -199,7 +599,7 @@ public actor SwamlRuntime {
     }
 
     /// Recursively update schema to include dynamic enum values
-    private func updateSchemaWithDynamicEnums(_ schema: JSONSchema, dynamicEnums: [String: [String]]) -> JSONSchema {
+    private nonisolated func updateSchemaWithDynamicEnums(_ schema: JSONSchema, dynamicEnums: [String: [String]]) -> JSONSchema {
         switch schema {
         case .ref(let name):
             // If this is a reference to a dynamic enum, replace with enum schema
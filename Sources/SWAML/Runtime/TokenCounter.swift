@@ -0,0 +1,122 @@
+import Foundation
+
+/// Model families with distinct tokenizers
+public enum ModelFamily: String, Sendable, Equatable {
+    case openAI
+    case anthropic
+    case gemini
+    case llama
+    case mistral
+    case other
+
+    /// Detect the family from a model name (e.g. "gpt-4o" or "anthropic/claude-sonnet-4")
+    public static func detect(_ model: String) -> ModelFamily {
+        let name = model.lowercased()
+        let base = name.split(separator: "/").last.map(String.init) ?? name
+
+        if base.hasPrefix("gpt") || base.hasPrefix("o1") || base.hasPrefix("o3") || base.hasPrefix("o4") {
+            return .openAI
+        }
+        if base.hasPrefix("claude") {
+            return .anthropic
+        }
+        if base.hasPrefix("gemini") || base.hasPrefix("gemma") {
+            return .gemini
+        }
+        if base.contains("llama") {
+            return .llama
+        }
+        if base.hasPrefix("mistral") || base.hasPrefix("mixtral") || base.hasPrefix("codestral") {
+            return .mistral
+        }
+        return .other
+    }
+
+    /// Average characters of English text per token
+    var charactersPerToken: Double {
+        switch self {
+        case .openAI, .gemini, .other:
+            return 4.0
+        case .anthropic:
+            return 3.5
+        case .llama, .mistral:
+            return 3.8
+        }
+    }
+}
+
+/// Approximate token counts for prompts.
+///
+/// Uses per-family character ratios rather than bundling tokenizer vocabularies, so counts
+/// are estimates (typically within 10-15% for English text). Non-ASCII characters, which
+/// tokenizers split more aggressively, are counted as one token each.
+public struct TokenCounter: Sendable {
+    public let family: ModelFamily
+
+    /// Tokens added per chat message for role and formatting
+    public static let messageOverhead = 4
+
+    public init(family: ModelFamily) {
+        self.family = family
+    }
+
+    public init(model: String) {
+        self.init(family: ModelFamily.detect(model))
+    }
+
+    /// Estimate tokens for a piece of text
+    public func count(_ text: String) -> Int {
+        var ascii = 0
+        var other = 0
+        for scalar in text.unicodeScalars {
+            if scalar.isASCII {
+                ascii += 1
+            } else {
+                other += 1
+            }
+        }
+        return Int((Double(ascii) / family.charactersPerToken).rounded(.up)) + other
+    }
+
+    /// Estimate tokens for a list of chat messages
+    public func count(_ messages: [ChatMessage]) -> Int {
+        messages.reduce(0) { total, message in
+            total + Self.messageOverhead + count(message.content.textValue ?? "")
+        }
+    }
+}
+
+/// Known context window sizes (in tokens)
+public enum ContextWindow {
+    /// Model name prefixes and their context windows. The longest matching prefix wins.
+    public static let knownModels: [String: Int] = [
+        "gpt-3.5-turbo": 16_385,
+        "gpt-4": 8_192,
+        "gpt-4-turbo": 128_000,
+        "gpt-4o": 128_000,
+        "gpt-4.1": 1_047_576,
+        "o1": 200_000,
+        "o3": 200_000,
+        "o4-mini": 200_000,
+        "claude": 200_000,
+        "gemini-1.5-pro": 2_097_152,
+        "gemini-1.5-flash": 1_048_576,
+        "gemini-2": 1_048_576,
+        "llama-3": 128_000,
+        "mistral-large": 128_000,
+        "mistral-small": 32_000
+    ]
+
+    /// Context window for a model, or nil if unknown
+    ///
+    /// Provider prefixes such as "openai/" are ignored.
+    public static func tokens(for model: String) -> Int? {
+        let name = model.lowercased()
+        let base = name.split(separator: "/").last.map(String.init) ?? name
+
+        return knownModels
+            .filter { base.hasPrefix($0.key) }
+            .max { $0.key.count < $1.key.count }?
+            .value
+    }
+}
@@ -26,6 +26,9 @@ public actor SwamlClient {
     private let llmClient: LLMClient
     private let typeBuilder: TypeBuilder
 
+    /// Options applied to every call, under any per-call options
+    public private(set) var defaultOptions = SwamlCallOptions()
+
     /// Initialize with an LLM provider
     public init(provider: LLMProvider) {
         self.llmClient = LLMClient(provider: provider)
@@ -61,6 +64,13 @@ public actor SwamlClient {
         SwamlClient(provider: .anthropic(apiKey: apiKey))
     }
 
+    // MARK: - Default Options
+
+    /// Set the options applied to every call. Per-call options override them field by field.
+    public func setDefaultOptions(_ options: SwamlCallOptions) {
+        defaultOptions = options
+    }
+
     // MARK: - Primary API
 
     /// Call an LLM with structured output
@@ -80,6 +90,31 @@ public actor SwamlClient {
         systemPrompt: String? = nil,
         temperature: Double? = nil,
         maxTokens: Int? = nil
+    ) async throws -> T {
+        try await call(
+            model: model,
+            prompt: prompt,
+            returnType: T.self,
+            systemPrompt: systemPrompt,
+            options: SwamlCallOptions(temperature: temperature, maxTokens: maxTokens)
+        )
+    }
+
+    /// Call an LLM with structured output and per-call options
+    ///
+    /// - Parameters:
+    ///   - model: The model identifier (e.g., "openai/gpt-4o-mini")
+    ///   - prompt: The user prompt
+    ///   - returnType: The expected return type (must conform to SwamlTyped)
+    ///   - systemPrompt: Optional additional system prompt (prepended to schema)
+    ///   - options: Sampling options, merged over the client's default options
+    /// - Returns: Parsed and validated response of the expected type
+    public func call<T: SwamlTyped>(
+        model: String,
+        prompt: String,
+        returnType: T.Type,
+        systemPrompt: String? = nil,
+        options: SwamlCallOptions
     ) async throws -> T {
         // 1. Build the schema prompt
         let schemaPrompt = SchemaPromptRenderer.render(
@@ -104,8 +139,7 @@ public actor SwamlClient {
                 .user(prompt)
             ],
             responseFormat: .jsonObject,
-            temperature: temperature,
-            maxTokens: maxTokens
+            options: defaultOptions.merging(options)
         )
 
         // 3. Parse with schema validation (use Rust parser if available)
@@ -134,6 +168,27 @@ public actor SwamlClient {
         temperature: Double? = nil,
         maxTokens: Int? = nil,
         maxRepairAttempts: Int = 1
+    ) async throws -> T {
+        try await callWithRepair(
+            model: model,
+            prompt: prompt,
+            returnType: T.self,
+            systemPrompt: systemPrompt,
+            options: SwamlCallOptions(temperature: temperature, maxTokens: maxTokens),
+            maxRepairAttempts: maxRepairAttempts
+        )
+    }
+
+    /// Call an LLM with structured output, per-call options and automatic error repair
+    ///
+    /// The repair request uses the same options, with temperature 0 unless one is set.
+    public func callWithRepair<T: SwamlTyped>(
+        model: String,
+        prompt: String,
+        returnType: T.Type,
+        systemPrompt: String? = nil,
+        options: SwamlCallOptions,
+        maxRepairAttempts: Int = 1
     ) async throws -> T {
         do {
             return try await call(
@@ -141,8 +196,7 @@ public actor SwamlClient {
                 prompt: prompt,
                 returnType: T.self,
                 systemPrompt: systemPrompt,
-                temperature: temperature,
-                maxTokens: maxTokens
+                options: options
             )
         } catch let error as SwamlError {
             guard maxRepairAttempts > 0 else { throw error }
@@ -164,7 +218,7 @@ public actor SwamlClient {
                 originalPrompt: prompt,
                 malformedOutput: rawOutput,
                 expectedSchema: T.swamlSchema,
-                temperature: temperature
+                options: options
             )
 
             // Retry parsing with repaired output
@@ -187,6 +241,21 @@ public actor SwamlClient {
         returnType: T.Type,
         temperature: Double? = nil,
         maxTokens: Int? = nil
+    ) async throws -> T {
+        try await call(
+            model: model,
+            prompt: prompt,
+            returnType: T.self,
+            options: SwamlCallOptions(temperature: temperature, maxTokens: maxTokens)
+        )
+    }
+
+    /// Call an LLM with PromptBuilder and per-call options
+    public func call<T: SwamlTyped>(
+        model: String,
+        prompt: PromptBuilder,
+        returnType: T.Type,
+        options: SwamlCallOptions
     ) async throws -> T {
         let messages = prompt.build(returnType: T.self, typeBuilder: typeBuilder)
 
@@ -194,8 +263,7 @@ public actor SwamlClient {
             model: model,
             messages: messages,
             responseFormat: .jsonObject,
-            temperature: temperature,
-            maxTokens: maxTokens
+            options: defaultOptions.merging(options)
         )
 
         return try parseResponse(response.content, schema: T.swamlSchema, type: T.self)
@@ -209,14 +277,30 @@ public actor SwamlClient {
         temperature: Double? = nil,
         maxTokens: Int? = nil,
         maxRepairAttempts: Int = 1
+    ) async throws -> T {
+        try await callWithRepair(
+            model: model,
+            prompt: prompt,
+            returnType: T.self,
+            options: SwamlCallOptions(temperature: temperature, maxTokens: maxTokens),
+            maxRepairAttempts: maxRepairAttempts
+        )
+    }
+
+    /// Call an LLM with PromptBuilder, per-call options and automatic error repair
+    public func callWithRepair<T: SwamlTyped>(
+        model: String,
+        prompt: PromptBuilder,
+        returnType: T.Type,
+        options: SwamlCallOptions,
+        maxRepairAttempts: Int = 1
     ) async throws -> T {
         do {
             return try await call(
                 model: model,
                 prompt: prompt,
                 returnType: T.self,
-                temperature: temperature,
-                maxTokens: maxTokens
+                options: options
             )
         } catch let error as SwamlError {
             guard maxRepairAttempts > 0 else { throw error }
@@ -234,7 +318,7 @@ public actor SwamlClient {
                 originalPrompt: prompt.buildRaw().compactMap { $0.content.textValue }.joined(separator: "\n"),
                 malformedOutput: rawOutput,
                 expectedSchema: T.swamlSchema,
-                temperature: temperature
+                options: options
             )
 
             return try parseResponse(repaired, schema: T.swamlSchema, type: T.self)
@@ -258,6 +342,23 @@ public actor SwamlClient {
         includeSchema: Bool = true,
         temperature: Double? = nil,
         maxTokens: Int? = nil
+    ) async throws -> T {
+        try await call(
+            model: model,
+            messages: messages,
+            returnType: T.self,
+            includeSchema: includeSchema,
+            options: SwamlCallOptions(temperature: temperature, maxTokens: maxTokens)
+        )
+    }
+
+    /// Call an LLM with custom messages and per-call options
+    public func call<T: SwamlTyped>(
+        model: String,
+        messages: [ChatMessage],
+        returnType: T.Type,
+        includeSchema: Bool = true,
+        options: SwamlCallOptions
     ) async throws -> T {
         var finalMessages = messages
 
@@ -281,8 +382,7 @@ public actor SwamlClient {
             model: model,
             messages: finalMessages,
             responseFormat: .jsonObject,
-            temperature: temperature,
-            maxTokens: maxTokens
+            options: defaultOptions.merging(options)
         )
 
         return try parseResponse(response.content, schema: T.swamlSchema, type: T.self)
@@ -305,6 +405,23 @@ public actor SwamlClient {
         systemPrompt: String? = nil,
         temperature: Double? = nil,
         maxTokens: Int? = nil
+    ) async throws -> SwamlValue {
+        try await callDynamic(
+            model: model,
+            prompt: prompt,
+            schema: schema,
+            systemPrompt: systemPrompt,
+            options: SwamlCallOptions(temperature: temperature, maxTokens: maxTokens)
+        )
+    }
+
+    /// Call an LLM with a dynamic schema and per-call options
+    public func callDynamic(
+        model: String,
+        prompt: String,
+        schema: JSONSchema,
+        systemPrompt: String? = nil,
+        options: SwamlCallOptions
     ) async throws -> SwamlValue {
         let schemaPrompt = SchemaPromptRenderer.render(
             schema: schema,
@@ -325,8 +442,7 @@ public actor SwamlClient {
                 .user(prompt)
             ],
             responseFormat: .jsonObject,
-            temperature: temperature,
-            maxTokens: maxTokens
+            options: defaultOptions.merging(options)
         )
 
         return try OutputParser.parseToValue(response.content, schema: schema)
@@ -392,13 +508,27 @@ public actor SwamlClient {
         responseFormat: ResponseFormat? = nil,
         temperature: Double? = nil,
         maxTokens: Int? = nil
+    ) async throws -> LLMResponse {
+        try await rawComplete(
+            model: model,
+            messages: messages,
+            responseFormat: responseFormat,
+            options: SwamlCallOptions(temperature: temperature, maxTokens: maxTokens)
+        )
+    }
+
+    /// Call an LLM with per-call options and return the raw response
+    public func rawComplete(
+        model: String,
+        messages: [ChatMessage],
+        responseFormat: ResponseFormat? = nil,
+        options: SwamlCallOptions
     ) async throws -> LLMResponse {
         try await llmClient.complete(
             model: model,
             messages: messages,
             responseFormat: responseFormat,
-            temperature: temperature,
-            maxTokens: maxTokens
+            options: defaultOptions.merging(options)
         )
     }
 }
@@ -423,6 +553,27 @@ extension SwamlClient {
         systemPrompt: String? = nil,
         temperature: Double? = nil,
         maxConcurrency: Int = 5
+    ) async throws -> [Result<T, Error>] {
+        try await batch(
+            model: model,
+            prompts: prompts,
+            returnType: T.self,
+            systemPrompt: systemPrompt,
+            options: SwamlCallOptions(temperature: temperature),
+            maxConcurrency: maxConcurrency
+        )
+    }
+
+    /// Call an LLM multiple times concurrently with per-call options
+    ///
+    /// `options` applies to every prompt, merged over the client's default options.
+    public func batch<T: SwamlTyped>(
+        model: String,
+        prompts: [String],
+        returnType: T.Type,
+        systemPrompt: String? = nil,
+        options: SwamlCallOptions,
+        maxConcurrency: Int = 5
     ) async throws -> [Result<T, Error>] {
         try await withThrowingTaskGroup(of: (Int, Result<T, Error>).self) { group in
             var results: [(Int, Result<T, Error>)] = []
@@ -439,7 +590,7 @@ extension SwamlClient {
                             prompt: prompt,
                             returnType: returnType,
                             systemPrompt: systemPrompt,
-                            temperature: temperature
+                            options: options
                         )
                         return (currentIndex, .success(result))
                     } catch {
@@ -505,7 +656,7 @@ extension SwamlClient {
         originalPrompt: String,
         malformedOutput: String,
         expectedSchema: JSONSchema,
-        temperature: Double?
+        options: SwamlCallOptions
     ) async throws -> String {
         let schemaText = SchemaPromptRenderer.renderSchema(expectedSchema, typeBuilder: typeBuilder)
 
@@ -522,6 +673,11 @@ extension SwamlClient {
             Return ONLY the corrected JSON, no explanation or markdown.
             """
 
+        var repairOptions = defaultOptions.merging(options)
+        if repairOptions.temperature == nil {
+            repairOptions.temperature = 0.0  // Use low temperature for repairs
+        }
+
         let response = try await llmClient.complete(
             model: model,
             messages: [
@@ -529,8 +685,7 @@ extension SwamlClient {
                 .user(repairPrompt)
             ],
             responseFormat: .jsonObject,
-            temperature: temperature ?? 0.0,  // Use low temperature for repairs
-            maxTokens: nil
+            options: repairOptions
         )
 
         return response.content
@@ -0,0 +1,321 @@
+import Foundation
+
+// MARK: - JSON Schema Import
+
+extension TypeBuilder {
+    /// Create dynamic classes and enums from a JSON Schema document
+    ///
+    /// Objects with `properties` become classes, string `enum`s and `oneOf`/`anyOf` lists of
+    /// string `const`s become enums, and `$defs`/`definitions` entries keep their names.
+    /// Inline types are named from their `title`, or from the parent type and property name.
+    /// `description` is copied to properties and enum values, and the `x-alias` extension
+    /// sets aliases. Properties that are not `required` become optional.
+    ///
+    /// - Parameters:
+    ///   - schema: The decoded JSON Schema
+    ///   - rootName: Name for the root type (defaults to its `title`, then "Root")
+    /// - Returns: The field type of the root schema
+    /// - Throws: TypeBuilderError.invalidSchema for constructs that cannot be represented, or
+    ///   when two types (or a type and one already on this builder) would share a name
+    @discardableResult
+    public func importJSONSchema(_ schema: [String: Any], rootName: String? = nil) throws -> FieldType {
+        var importer = JSONSchemaImporter(builder: self, root: schema, rootName: rootName)
+        return try importer.importRoot()
+    }
+
+    /// Create dynamic classes and enums from JSON Schema data
+    @discardableResult
+    public func importJSONSchema(_ data: Data, rootName: String? = nil) throws -> FieldType {
+        guard let schema = try JSONSerialization.jsonObject(with: data) as? [String: Any] else {
+            throw TypeBuilderError.invalidSchema("Schema root must be a JSON object")
+        }
+        return try importJSONSchema(schema, rootName: rootName)
+    }
+}
+
+/// Walks a JSON Schema and registers the types it defines on a TypeBuilder
+private struct JSONSchemaImporter {
+    let builder: TypeBuilder
+    let root: [String: Any]
+    let rootName: String
+    let definitions: [String: [String: Any]]
+
+    /// Converted definitions by name
+    private var resolved: [String: FieldType] = [:]
+
+    /// Definitions currently being converted (for recursive references)
+    private var inProgress: Set<String> = []
+
+    /// Class and enum names created by this import
+    private var created: Set<String> = []
+
+    init(builder: TypeBuilder, root: [String: Any], rootName: String?) {
+        self.builder = builder
+        self.root = root
+        self.rootName = rootName ?? (root["title"] as? String).map(JSONSchemaImporter.typeName) ?? "Root"
+
+        var definitions: [String: [String: Any]] = [:]
+        for key in ["definitions", "$defs"] {
+            if let defs = root[key] as? [String: Any] {
+                for (name, value) in defs {
+                    if let def = value as? [String: Any] {
+                        definitions[name] = def
+                    }
+                }
+            }
+        }
+        self.definitions = definitions
+    }
+
+    mutating func importRoot() throws -> FieldType {
+        try convert(untitled(root), name: rootName)
+    }
+
+    // MARK: - Conversion
+
+    private mutating func convert(_ schema: [String: Any], name: String) throws -> FieldType {
+        if let ref = schema["$ref"] as? String {
+            return try resolveReference(ref)
+        }
+
+        if let value = schema["const"] {
+            return try literal(value)
+        }
+
+        if let values = schema["enum"] as? [Any] {
+            return try convertEnum(values, schema: schema, name: name)
+        }
+
+        if let variants = (schema["oneOf"] ?? schema["anyOf"]) as? [[String: Any]] {
+            return try convertVariants(variants, schema: schema, name: name)
+        }
+
+        if let allOf = schema["allOf"] as? [[String: Any]] {
+            guard allOf.count == 1 else {
+                throw TypeBuilderError.invalidSchema("allOf with multiple schemas is not supported (\(name))")
+            }
+            return try convert(allOf[0], name: name)
+        }
+
+        if let types = schema["type"] as? [String] {
+            let nonNull = types.filter { $0 != "null" }
+            guard !nonNull.isEmpty else {
+                return .null
+            }
+            let options = try nonNull.map { type -> FieldType in
+                var single = schema
+                single["type"] = type
+                return try convert(single, name: name)
+            }
+            let inner = options.count == 1 ? options[0] : .union(options)
+            return types.contains("null") ? .optional(inner) : inner
+        }
+
+        switch schema["type"] as? String {
+        case "string":
+            return .string
+        case "integer":
+            return .int
+        case "number":
+            return .float
+        case "boolean":
+            return .bool
+        case "null":
+            return .null
+        case "array":
+            guard let items = schema["items"] as? [String: Any] else {
+                throw TypeBuilderError.invalidSchema("Array schema without items (\(name))")
+            }
+            return .list(try convert(items, name: name + "Item"))
+        case "object", nil where schema["properties"] != nil:
+            return try convertObject(schema, name: name)
+        default:
+            throw TypeBuilderError.invalidSchema("Unsupported schema for \(name)")
+        }
+    }
+
+    private mutating func convertObject(_ schema: [String: Any], name: String) throws -> FieldType {
+        guard let properties = schema["properties"] as? [String: Any] else {
+            if let additional = schema["additionalProperties"] as? [String: Any] {
+                return .map(key: .string, value: try convert(additional, name: name + "Value"))
+            }
+            throw TypeBuilderError.invalidSchema("Object schema without properties or additionalProperties (\(name))")
+        }
+
+        let className = try claim((schema["title"] as? String).map(JSONSchemaImporter.typeName) ?? name)
+        let classBuilder = builder.addClass(className)
+        let required = Set(schema["required"] as? [String] ?? [])
+
+        for propertyName in properties.keys.sorted() {
+            guard let propertySchema = properties[propertyName] as? [String: Any] else {
+                throw TypeBuilderError.invalidSchema("Property '\(propertyName)' of \(className) must be a schema object")
+            }
+
+            var type = try convert(
+                propertySchema,
+                name: className + JSONSchemaImporter.typeName(propertyName)
+            )
+            if !required.contains(propertyName), !type.isOptional {
+                type = .optional(type)
+            }
+
+            let property = classBuilder.addProperty(propertyName, type)
+            if let description = propertySchema["description"] as? String {
+                property.description(description)
+            }
+            if let alias = propertySchema["x-alias"] as? String {
+                property.alias(alias)
+            }
+        }
+
+        return classBuilder.type()
+    }
+
+    private mutating func convertEnum(_ values: [Any], schema: [String: Any], name: String) throws -> FieldType {
+        let nullable = values.contains { $0 is NSNull }
+        let nonNull = values.filter { !($0 is NSNull) }
+
+        let type: FieldType
+        if let strings = nonNull as? [String] {
+            let enumBuilder = builder.addEnum(
+                try claim((schema["title"] as? String).map(JSONSchemaImporter.typeName) ?? name)
+            )
+            for value in strings {
+                enumBuilder.addValue(value)
+            }
+            type = enumBuilder.type()
+        } else {
+            let literals = try nonNull.map { try literal($0) }
+            type = literals.count == 1 ? literals[0] : .union(literals)
+        }
+
+        return nullable ? .optional(type) : type
+    }
+
+    private mutating func convertVariants(
+        _ variants: [[String: Any]],
+        schema: [String: Any],
+        name: String
+    ) throws -> FieldType {
+        let nonNull = variants.filter { ($0["type"] as? String) != "null" }
+        let nullable = nonNull.count < variants.count
+
+        // A list of string constants is an enum with per-value metadata
+        let constants = nonNull.compactMap { $0["const"] as? String }
+        if !constants.isEmpty, constants.count == nonNull.count {
+            let enumBuilder = builder.addEnum(
+                try claim((schema["title"] as? String).map(JSONSchemaImporter.typeName) ?? name)
+            )
+            for variant in nonNull {
+                guard let value = variant["const"] as? String else { continue }
+                let valueBuilder = enumBuilder.addValue(value)
+                if let description = variant["description"] as? String {
+                    valueBuilder.description(description)
+                }
+                if let alias = variant["x-alias"] as? String {
+                    valueBuilder.alias(alias)
+                }
+            }
+            return nullable ? .optional(enumBuilder.type()) : enumBuilder.type()
+        }
+
+        var options: [FieldType] = []
+        for (index, variant) in nonNull.enumerated() {
+            options.append(try convert(variant, name: name + "Option\(index + 1)"))
+        }
+
+        guard !options.isEmpty else {
+            return .null
+        }
+        let inner = options.count == 1 ? options[0] : .union(options)
+        return nullable ? .optional(inner) : inner
+    }
+
+    // MARK: - References
+
+    private mutating func resolveReference(_ ref: String) throws -> FieldType {
+        if ref == "#" {
+            return .reference(rootName)
+        }
+
+        guard let definitionName = ["#/$defs/", "#/definitions/"]
+            .first(where: { ref.hasPrefix($0) })
+            .map({ String(ref.dropFirst($0.count)) }) else {
+            throw TypeBuilderError.invalidSchema("Unsupported $ref '\(ref)'")
+        }
+
+        if let type = resolved[definitionName] {
+            return type
+        }
+        if inProgress.contains(definitionName) {
+            return .reference(definitionName)
+        }
+        guard let definition = definitions[definitionName] else {
+            throw TypeBuilderError.invalidSchema("Unresolved $ref '\(ref)'")
+        }
+
+        inProgress.insert(definitionName)
+        defer { inProgress.remove(definitionName) }
+
+        let type = try convert(untitled(definition), name: definitionName)
+        resolved[definitionName] = type
+        return type
+    }
+
+    // MARK: - Helpers
+
+    /// Reserve a name for a new class or enum
+    ///
+    /// TypeBuilder returns the existing builder for a known name, so two different schemas
+    /// with the same name would silently be merged into one type.
+    private mutating func claim(_ name: String) throws -> String {
+        let exists = builder.buildClassSchema(name) != nil || builder.buildEnumSchema(name) != nil
+        guard !created.contains(name), !exists else {
+            throw TypeBuilderError.invalidSchema("Duplicate type name '\(name)'; give the schemas distinct titles")
+        }
+        created.insert(name)
+        return name
+    }
+
+    /// Drop `title` so the definition key (not the title) names the type
+    private func untitled(_ schema: [String: Any]) -> [String: Any] {
+        var schema = schema
+        schema.removeValue(forKey: "title")
+        return schema
+    }
+
+    private func literal(_ value: Any) throws -> FieldType {
+        switch SwamlValue.fromJSON(value) {
+        case .string(let string):
+            return .literalString(string)
+        case .int(let int):
+            return .literalInt(int)
+        case .bool(let bool):
+            return .literalBool(bool)
+        case .null:
+            return .null
+        default:
+            throw TypeBuilderError.invalidSchema("Unsupported literal value '\(value)'")
+        }
+    }
+
+    /// Convert "line_items" or "line items" to "LineItems"
+    static func typeName(_ raw: String) -> String {
+        raw.split { !$0.isLetter && !$0.isNumber }
+            .map { $0.prefix(1).uppercased() + $0.dropFirst() }
+            .joined()
+    }
+}
+
+private extension FieldType {
+    var isOptional: Bool {
+        switch self {
+        case .optional:
+            return true
+        case .union(let types):
+            return types.contains(.null)
+        default:
+            return false
+        }
+    }
+}
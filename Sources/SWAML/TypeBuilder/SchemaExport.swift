@@ -0,0 +1,57 @@
+import Foundation
+
+// MARK: - JSON Schema Export
+
+extension TypeBuilder {
+    /// Build a self-contained JSON Schema document for `root`
+    ///
+    /// Every dynamic class and enum the schema references, directly or through other
+    /// definitions, is emitted under `$defs`. References to types this builder does not
+    /// know are left as-is.
+    public func jsonSchemaDocument(for root: JSONSchema) -> [String: Any] {
+        JSONSchema.document(root: root, definitions: referencedDefinitions(from: root))
+    }
+
+    /// Build a self-contained JSON Schema document for a field type
+    public func jsonSchemaDocument(for type: FieldType) -> [String: Any] {
+        jsonSchemaDocument(for: type.toJSONSchema())
+    }
+
+    /// Collect schemas for all dynamic types reachable from `root`
+    func referencedDefinitions(from root: JSONSchema) -> [String: JSONSchema] {
+        var definitions: [String: JSONSchema] = [:]
+        var pending = root.referencedNames
+
+        while let name = pending.popLast() {
+            if definitions[name] != nil {
+                continue
+            }
+            guard let schema = buildClassSchema(name) ?? buildEnumSchema(name) else {
+                continue
+            }
+            definitions[name] = schema
+            pending.append(contentsOf: schema.referencedNames)
+        }
+
+        return definitions
+    }
+}
+
+extension JSONSchema {
+    /// Names of all `$ref` targets in this schema
+    var referencedNames: [String] {
+        switch self {
+        case .ref(let name):
+            return [name]
+        case .array(let items):
+            return items.referencedNames
+        case .object(let properties, _, let additionalProperties):
+            let fromProperties = properties.keys.sorted().flatMap { properties[$0]?.referencedNames ?? [] }
+            return fromProperties + (additionalProperties?.referencedNames ?? [])
+        case .anyOf(let schemas):
+            return schemas.flatMap { $0.referencedNames }
+        case .string, .integer, .number, .boolean, .null, .enum:
+            return []
+        }
+    }
+}
@@ -290,6 +290,57 @@ open class TypeBuilder: @unchecked Sendable {
         return result
     }
 
+    // MARK: - Path Resolution
+
+    /// Resolve a type path such as `"Resume"` or `"Resume.skills"` to its field type.
+    ///
+    /// A bare name resolves to a reference to a known or dynamic class or enum. Each further
+    /// segment names a property of a dynamic class; optionals and lists are looked through when
+    /// following a property into the next class.
+    public func resolveType(_ path: String) throws -> FieldType {
+        let segments = path.split(separator: ".", omittingEmptySubsequences: false).map(String.init)
+        guard !segments.contains(where: \.isEmpty) else {
+            throw TypeBuilderError.invalidPath(path)
+        }
+        let typeName = segments[0]
+
+        lock.lock()
+        let classes = classBuilders
+        let isKnownType = classBuilders[typeName] != nil
+            || enumBuilders[typeName] != nil
+            || knownClasses.contains(typeName)
+            || knownEnums.contains(typeName)
+        lock.unlock()
+
+        guard isKnownType else {
+            throw TypeBuilderError.typeNotRegistered(typeName)
+        }
+
+        var resolved = FieldType.reference(typeName)
+        var resolvedPath = typeName
+        for property in segments.dropFirst() {
+            guard let className = Self.referencedName(resolved),
+                  let propertyBuilder = classes[className]?.propertyBuilder(property) else {
+                throw TypeBuilderError.propertyNotFound(type: resolvedPath, property: property)
+            }
+            resolved = propertyBuilder.fieldType
+            resolvedPath += ".\(property)"
+        }
+        return resolved
+    }
+
+    /// The class or enum a field type points to, looking through optionals and lists
+    private static func referencedName(_ type: FieldType) -> String? {
+        switch type {
+        case .reference(let name):
+            return name
+        case .optional(let inner), .list(let inner):
+            return referencedName(inner)
+        default:
+            return nil
+        }
+    }
+
     // MARK: - FFI Serialization
 
     /// Serialize the TypeBuilder state for FFI
@@ -332,6 +383,9 @@ public enum TypeBuilderError: Error, LocalizedError {
     case serializationFailed
     case typeNotDynamic(String)
     case typeNotRegistered(String)
+    case invalidSchema(String)
+    case invalidPath(String)
+    case propertyNotFound(type: String, property: String)
 
     public var errorDescription: String? {
         switch self {
@@ -341,6 +395,12 @@ public enum TypeBuilderError: Error, LocalizedError {
             return "Cannot extend non-dynamic type '\(name)'. Add @SwamlDynamic to allow runtime extension."
         case .typeNotRegistered(let name):
             return "Type '\(name)' is not registered for dynamic extension"
+        case .invalidSchema(let message):
+            return "Cannot import JSON Schema: \(message)"
+        case .invalidPath(let path):
+            return "Invalid type path '\(path)'. Expected 'Type' or 'Type.property'."
+        case .propertyNotFound(let type, let property):
+            return "'\(type)' has no dynamic property '\(property)'"
         }
     }
 }
@@ -619,6 +679,13 @@ public final class DynamicClassBuilder: @unchecked Sendable {
         return propertyBuilders[propertyName] != nil
     }
 
+    /// Get the builder for a property, if it exists
+    public func propertyBuilder(_ propertyName: String) -> ClassPropertyBuilder? {
+        lock.lock()
+        defer { lock.unlock() }
+        return propertyBuilders[propertyName]
+    }
+
     /// Get the count of properties
     public var count: Int {
         lock.lock()
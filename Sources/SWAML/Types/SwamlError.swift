@@ -8,6 +8,15 @@ public enum SwamlError: Error, LocalizedError, Sendable {
     /// API returned an error response
     case apiError(statusCode: Int, message: String)
 
+    /// Request exceeded its total deadline
+    case requestTimeout(seconds: TimeInterval)
+
+    /// Prompt (plus requested output) does not fit the model's context window
+    case contextWindowExceeded(tokens: Int, limit: Int)
+
+    /// Image or other media input is unreadable, too large, or of an unsupported type
+    case invalidMedia(String)
+
     /// Failed to parse LLM output
     case parseError(String)
 
@@ -47,6 +56,12 @@ public enum SwamlError: Error, LocalizedError, Sendable {
             return "Network error: \(message)"
         case .apiError(let statusCode, let message):
             return "API error (\(statusCode)): \(message)"
+        case .requestTimeout(let seconds):
+            return "Request timed out after \(seconds)s"
+        case .contextWindowExceeded(let tokens, let limit):
+            return "Prompt needs about \(tokens) tokens, exceeding the \(limit)-token context window"
+        case .invalidMedia(let message):
+            return "Invalid media: \(message)"
         case .parseError(let message):
             return "Parse error: \(message)"
         case .jsonExtractionError(let message):
@@ -36,6 +36,9 @@ import SWAML
 /// - `fieldDescriptions`: Property descriptions from `@Description`
 /// - `isDynamic`: Whether the type can be extended at runtime
 ///
+/// For enums it also generates `swamlDescription` and `swamlAlias` for each case, and
+/// `init?(swamlAlias:)`, which finds the case whose alias (or name, if it has none) matches.
+///
 /// Example:
 /// ```swift
 /// @SwamlType
@@ -58,7 +61,7 @@ import SWAML
 ///     static var isDynamic: Bool { false }
 /// }
 /// ```
-@attached(extension, conformances: SwamlTyped, names: named(swamlTypeName), named(swamlSchema), named(isDynamic), named(fieldDescriptions), named(fieldAliases))
+@attached(extension, conformances: SwamlTyped, names: named(swamlTypeName), named(swamlSchema), named(isDynamic), named(fieldDescriptions), named(fieldAliases), named(swamlDescription), named(swamlAlias), named(init(swamlAlias:)))
 public macro SwamlType() = #externalMacro(module: "SwamlMacrosPlugin", type: "SwamlTypeMacro")
 
 // MARK: - SwamlDynamic Macro
@@ -121,7 +124,7 @@ public macro Description(_ description: String) = #externalMacro(module: "SwamlM
 
 // MARK: - Alias Macro
 
-/// Provides an alternative name for a property in LLM output.
+/// Provides an alternative name for a property or enum case in LLM output.
 ///
 /// The alias is used when parsing LLM responses - if the LLM outputs the alias
 /// instead of the property name, it will still be parsed correctly.
@@ -0,0 +1,95 @@
+import Foundation
+import SwiftDiagnostics
+import SwiftSyntax
+import SwiftSyntaxMacros
+
+/// Naming problems that would make @SwamlType generate a broken or ambiguous schema
+enum SwamlTypeDiagnostic: DiagnosticMessage {
+    case emptyAlias(member: String)
+    case duplicateName(String, member: String, existing: String)
+    case reservedName(String)
+
+    var message: String {
+        switch self {
+        case .emptyAlias(let member):
+            return "@Alias for '\(member)' is empty"
+        case .duplicateName(let name, let member, let existing):
+            return "'\(member)' appears in the schema as '\(name)', which clashes with '\(existing)' (names are compared case-insensitively)"
+        case .reservedName(let name):
+            return "'\(name)' clashes with a member generated by @SwamlType; rename it"
+        }
+    }
+
+    var diagnosticID: MessageID {
+        switch self {
+        case .emptyAlias:
+            return MessageID(domain: "SwamlMacros", id: "emptyAlias")
+        case .duplicateName:
+            return MessageID(domain: "SwamlMacros", id: "duplicateName")
+        case .reservedName:
+            return MessageID(domain: "SwamlMacros", id: "reservedName")
+        }
+    }
+
+    var severity: DiagnosticSeverity { .error }
+}
+
+/// A property or enum case as it will appear in the schema
+struct SchemaMember {
+    let name: String
+    let alias: String?
+    let nameNode: Syntax
+    let aliasNode: Syntax?
+
+    /// The name the model sees
+    var schemaName: String { alias ?? name }
+}
+
+enum SchemaNameValidator {
+    /// Members @SwamlType adds to the type. Enum cases with these names would be redeclarations.
+    static let generatedMembers: Set<String> = [
+        "swamlTypeName", "swamlSchema", "isDynamic", "fieldDescriptions", "fieldAliases",
+        "swamlDescription", "swamlAlias",
+    ]
+
+    /// Report every naming problem at the offending member.
+    ///
+    /// - Returns: false if any problem was found, in which case nothing should be generated
+    static func validate(
+        _ members: [SchemaMember],
+        reserved: Set<String> = [],
+        in context: some MacroExpansionContext
+    ) -> Bool {
+        var isValid = true
+        var seen: [String: String] = [:]
+
+        for member in members {
+            if reserved.contains(member.name) {
+                context.diagnose(Diagnostic(node: member.nameNode, message: SwamlTypeDiagnostic.reservedName(member.name)))
+                isValid = false
+            }
+
+            if let alias = member.alias, alias.trimmingCharacters(in: .whitespaces).isEmpty {
+                context.diagnose(Diagnostic(
+                    node: member.aliasNode ?? member.nameNode,
+                    message: SwamlTypeDiagnostic.emptyAlias(member: member.name)
+                ))
+                isValid = false
+                continue
+            }
+
+            let folded = member.schemaName.lowercased()
+            if let existing = seen[folded] {
+                context.diagnose(Diagnostic(
+                    node: member.aliasNode ?? member.nameNode,
+                    message: SwamlTypeDiagnostic.duplicateName(member.schemaName, member: member.name, existing: existing)
+                ))
+                isValid = false
+            } else {
+                seen[folded] = member.name
+            }
+        }
+
+        return isValid
+    }
+}
@@ -36,6 +36,8 @@ public struct SwamlTypeMacro: ExtensionMacro {
         var properties: [(name: String, type: String, isOptional: Bool)] = []
         var descriptions: [String: String] = [:]
         var aliases: [String: String] = [:]
+        var nameNodes: [String: Syntax] = [:]
+        var aliasNodes: [String: Syntax] = [:]
 
         for member in structDecl.memberBlock.members {
             guard let varDecl = member.decl.as(VariableDeclSyntax.self) else { continue }
@@ -65,6 +67,7 @@ public struct SwamlTypeMacro: ExtensionMacro {
                         if let binding = varDecl.bindings.first,
                            let pattern = binding.pattern.as(IdentifierPatternSyntax.self) {
                             aliases[pattern.identifier.text] = segment.content.text
+                            aliasNodes[pattern.identifier.text] = Syntax(attrSyntax)
                         }
                     }
                 }
@@ -80,9 +83,23 @@ public struct SwamlTypeMacro: ExtensionMacro {
                 let isOptional = propType.hasSuffix("?") || propType.hasPrefix("Optional<")
 
                 properties.append((name: propName, type: propType, isOptional: isOptional))
+                nameNodes[propName] = Syntax(pattern)
             }
         }
 
+        let members = properties.compactMap { property -> SchemaMember? in
+            guard let nameNode = nameNodes[property.name] else { return nil }
+            return SchemaMember(
+                name: property.name,
+                alias: aliases[property.name],
+                nameNode: nameNode,
+                aliasNode: aliasNodes[property.name]
+            )
+        }
+        guard SchemaNameValidator.validate(members, in: context) else {
+            return []
+        }
+
         // Check for @SwamlDynamic attribute
         let isDynamic = structDecl.attributes.contains { attr in
             guard let attrSyntax = attr.as(AttributeSyntax.self) else { return false }
@@ -125,11 +142,13 @@ public struct SwamlTypeMacro: ExtensionMacro {
         // Extract enum cases
         var cases: [String] = []
         var descriptions: [String: String] = [:]
+        var aliases: [String: String] = [:]
+        var members: [SchemaMember] = []
 
         for member in enumDecl.memberBlock.members {
             guard let caseDecl = member.decl.as(EnumCaseDeclSyntax.self) else { continue }
 
-            // Check for @Description attribute on the case
+            // Check for @Description and @Alias attributes on the case
             for attr in caseDecl.attributes {
                 if let attrSyntax = attr.as(AttributeSyntax.self) {
                     let attrName = attrSyntax.attributeName.description.trimmingCharacters(in: .whitespaces)
@@ -143,14 +162,38 @@ public struct SwamlTypeMacro: ExtensionMacro {
                             descriptions[element.name.text] = segment.content.text
                         }
                     }
+
+                    if attrName == "Alias",
+                       let args = attrSyntax.arguments?.as(LabeledExprListSyntax.self),
+                       let firstArg = args.first,
+                       let stringLiteral = firstArg.expression.as(StringLiteralExprSyntax.self),
+                       let segment = stringLiteral.segments.first?.as(StringSegmentSyntax.self) {
+                        for element in caseDecl.elements {
+                            aliases[element.name.text] = segment.content.text
+                        }
+                    }
                 }
             }
 
+            let aliasNode = caseDecl.attributes.first { attr in
+                attr.as(AttributeSyntax.self)?.attributeName.description.trimmingCharacters(in: .whitespaces) == "Alias"
+            }
+
             for element in caseDecl.elements {
                 cases.append(element.name.text)
+                members.append(SchemaMember(
+                    name: element.name.text,
+                    alias: aliases[element.name.text],
+                    nameNode: Syntax(element),
+                    aliasNode: aliasNode.map { Syntax($0) }
+                ))
             }
         }
 
+        guard SchemaNameValidator.validate(members, reserved: SchemaNameValidator.generatedMembers, in: context) else {
+            return []
+        }
+
         // Check for @SwamlDynamic attribute
         let isDynamic = enumDecl.attributes.contains { attr in
             guard let attrSyntax = attr.as(AttributeSyntax.self) else { return false }
@@ -161,6 +204,12 @@ public struct SwamlTypeMacro: ExtensionMacro {
         let enumValues = cases.map { "\"\($0)\"" }.joined(separator: ", ")
 
         let descriptionsCode = buildDictionaryLiteral(descriptions)
+        let aliasesCode = buildDictionaryLiteral(aliases)
+
+        // Per-case labels, so apps can show the same text the prompt gives the model
+        let descriptionCases = buildCaseLabels(cases, labels: descriptions)
+        let aliasCases = buildCaseLabels(cases, labels: aliases)
+        let aliasLookup = cases.map { "case \"\(aliases[$0] ?? $0)\": self = .\($0)" }
 
         let extensionDecl: DeclSyntax = """
         extension \(raw: typeName): SwamlTyped {
@@ -168,7 +217,22 @@ public struct SwamlTypeMacro: ExtensionMacro {
             public static var swamlSchema: JSONSchema { .enum(values: [\(raw: enumValues)]) }
             public static var isDynamic: Bool { \(raw: isDynamic ? "true" : "false") }
             public static var fieldDescriptions: [String: String] { \(raw: descriptionsCode) }
-            public static var fieldAliases: [String: String] { [:] }
+            public static var fieldAliases: [String: String] { \(raw: aliasesCode) }
+            public var swamlDescription: String? {
+                switch self {
+                \(raw: descriptionCases.joined(separator: "\n"))
+                }
+            }
+            public var swamlAlias: String? {
+                switch self {
+                \(raw: aliasCases.joined(separator: "\n"))
+                }
+            }
+            public init?(swamlAlias alias: String) {
+                switch alias {
+                \(raw: (aliasLookup + ["default: return nil"]).joined(separator: "\n"))
+                }
+            }
         }
         """
 
@@ -252,6 +316,15 @@ public struct SwamlTypeMacro: ExtensionMacro {
         }
     }
 
+    private static func buildCaseLabels(_ cases: [String], labels: [String: String]) -> [String] {
+        cases.map { name in
+            if let label = labels[name] {
+                return "case .\(name): return \"\(label)\""
+            }
+            return "case .\(name): return nil"
+        }
+    }
+
     private static func buildDictionaryLiteral(_ dict: [String: String]) -> String {
         if dict.isEmpty {
             return "[:]"
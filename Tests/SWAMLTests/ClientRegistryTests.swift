@@ -0,0 +1,57 @@
+import XCTest
+@testable import SWAML
+
+final class ClientRegistryTests: XCTestCase {
+
+    // MARK: - Client References
+
+    func testResolveRegisteredName() async {
+        let registry = ClientRegistry()
+        await registry.register(name: "fast", provider: .openAI(apiKey: "key"), model: "gpt-4o-mini")
+
+        let config = await registry.resolve("fast")
+
+        XCTAssertEqual(config?.model, "gpt-4o-mini")
+    }
+
+    func testResolveProviderModelShorthand() async {
+        let registry = ClientRegistry()
+        await registry.register(name: "default", provider: .openAI(apiKey: "key"), model: "gpt-4o", isDefault: true)
+        await registry.register(name: "claude", provider: .anthropic(apiKey: "key"), model: "claude-sonnet-4-20250514")
+
+        let openAI = await registry.resolve("openai/gpt-4o-mini")
+        XCTAssertEqual(openAI?.name, "default")
+        XCTAssertEqual(openAI?.model, "gpt-4o-mini")
+
+        let anthropic = await registry.resolve("Anthropic/claude-3-5-haiku-latest")
+        XCTAssertEqual(anthropic?.name, "claude")
+        XCTAssertEqual(anthropic?.model, "claude-3-5-haiku-latest")
+    }
+
+    func testResolveOpenRouterModelID() async {
+        let registry = ClientRegistry()
+        await registry.register(name: "default", provider: .openRouter(apiKey: "key"), model: "openai/gpt-4o")
+
+        let config = await registry.resolve("meta-llama/llama-3.1-70b-instruct")
+
+        XCTAssertEqual(config?.name, "default")
+        XCTAssertEqual(config?.model, "meta-llama/llama-3.1-70b-instruct")
+    }
+
+    func testResolveUnknownReference() async {
+        let registry = ClientRegistry()
+        await registry.register(name: "default", provider: .openAI(apiKey: "key"), model: "gpt-4o")
+
+        let unknownName = await registry.resolve("missing")
+        let unknownProvider = await registry.resolve("anthropic/claude-sonnet-4")
+        XCTAssertNil(unknownName)
+        XCTAssertNil(unknownProvider)
+    }
+
+    func testWithModelDropsContextWindowOverride() {
+        let config = ClientConfig(name: "default", provider: .openAI(apiKey: "key"), model: "gpt-4o", contextWindow: 1_000)
+
+        XCTAssertEqual(config.withModel("gpt-4o").contextWindow, 1_000)
+        XCTAssertNil(config.withModel("gpt-4.1").contextWindow)
+    }
+}
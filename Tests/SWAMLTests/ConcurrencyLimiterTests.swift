@@ -0,0 +1,95 @@
+import XCTest
+@testable import SWAML
+
+final class ConcurrencyLimiterTests: XCTestCase {
+
+    // MARK: - Limits
+
+    func testLimitsAreAtLeastOne() async {
+        let limiter = ConcurrencyLimiter(limits: ["Vision": 0])
+
+        let limit = await limiter.limit(for: "Vision")
+        XCTAssertEqual(limit, 1)
+    }
+
+    func testUnlimitedFunctionNeverQueues() async throws {
+        let limiter = ConcurrencyLimiter()
+
+        for _ in 0..<10 {
+            let queued = try await limiter.acquire("Extract")
+            XCTAssertFalse(queued)
+        }
+        let active = await limiter.activeCount(for: "Extract")
+        XCTAssertEqual(active, 10)
+    }
+
+    // MARK: - Queueing
+
+    func testCallsBeyondLimitWaitForRelease() async throws {
+        let limiter = ConcurrencyLimiter(limits: ["Vision": 1])
+
+        let first = try await limiter.acquire("Vision")
+        XCTAssertFalse(first)
+
+        let waiter = Task { try await limiter.acquire("Vision") }
+        while await limiter.queuedCount(for: "Vision") == 0 {
+            await Task.yield()
+        }
+
+        await limiter.release("Vision")
+        let queued = try await waiter.value
+
+        XCTAssertTrue(queued)
+        let active = await limiter.activeCount(for: "Vision")
+        let waiting = await limiter.queuedCount(for: "Vision")
+        XCTAssertEqual(active, 1)
+        XCTAssertEqual(waiting, 0)
+    }
+
+    func testRaisingLimitStartsWaitingCalls() async throws {
+        let limiter = ConcurrencyLimiter(limits: ["Vision": 1])
+        _ = try await limiter.acquire("Vision")
+
+        let waiter = Task { try await limiter.acquire("Vision") }
+        while await limiter.queuedCount(for: "Vision") == 0 {
+            await Task.yield()
+        }
+
+        await limiter.setLimit(2, for: "Vision")
+        _ = try await waiter.value
+
+        let active = await limiter.activeCount(for: "Vision")
+        XCTAssertEqual(active, 2)
+    }
+
+    func testCancelledWaiterLeavesQueue() async throws {
+        let limiter = ConcurrencyLimiter(limits: ["Vision": 1])
+        _ = try await limiter.acquire("Vision")
+
+        let waiter = Task { try await limiter.acquire("Vision") }
+        while await limiter.queuedCount(for: "Vision") == 0 {
+            await Task.yield()
+        }
+        waiter.cancel()
+
+        do {
+            _ = try await waiter.value
+            XCTFail("Expected CancellationError")
+        } catch is CancellationError {
+            // expected
+        }
+        let waiting = await limiter.queuedCount(for: "Vision")
+        XCTAssertEqual(waiting, 0)
+    }
+
+    // MARK: - Metrics
+
+    func testQueueWaitMetrics() {
+        let metrics = RuntimeMetrics()
+        metrics.recordQueueWait(function: "Vision", duration: 1.5)
+
+        XCTAssertEqual(metrics.queuedCallCount(function: "Vision"), 1)
+        XCTAssertEqual(metrics.totalQueueWait(function: "Vision"), 1.5, accuracy: 0.0001)
+        XCTAssertTrue(metrics.prometheusText().contains("swaml_queued_calls_total{function=\"Vision\"} 1"))
+    }
+}
@@ -0,0 +1,85 @@
+import XCTest
+@testable import SWAML
+
+final class ImageInputTests: XCTestCase {
+
+    /// A 1x1 PNG
+    let pngBase64 = "iVBORw0KGgoAAAANSUhEUgAAAAEAAAABCAYAAAAfFcSJAAAADUlEQVR42mNkYPhfDwAChwGA60e6kgAAAABJRU5ErkJggg=="
+
+    // MARK: - Media Type Detection
+
+    func testDetectMediaTypes() {
+        XCTAssertEqual(ChatMessage.ContentPart.detectImageMediaType(Data(base64Encoded: pngBase64)!), "image/png")
+        XCTAssertEqual(ChatMessage.ContentPart.detectImageMediaType(Data([0xFF, 0xD8, 0xFF, 0xE0])), "image/jpeg")
+        XCTAssertEqual(ChatMessage.ContentPart.detectImageMediaType(Data("GIF89a".utf8)), "image/gif")
+        XCTAssertEqual(ChatMessage.ContentPart.detectImageMediaType(Data("RIFF\0\0\0\0WEBPVP8 ".utf8)), "image/webp")
+        XCTAssertNil(ChatMessage.ContentPart.detectImageMediaType(Data("hello".utf8)))
+    }
+
+    // MARK: - Raw Bytes
+
+    func testImageFromData() throws {
+        let part = try ChatMessage.ContentPart.image(data: Data(base64Encoded: pngBase64)!)
+
+        XCTAssertEqual(part, .imageBase64(data: pngBase64, mediaType: "image/png"))
+    }
+
+    func testRejectsUnknownFormat() {
+        XCTAssertThrowsError(try ChatMessage.ContentPart.image(data: Data("not an image".utf8))) { error in
+            guard case SwamlError.invalidMedia = error else {
+                return XCTFail("Expected invalidMedia, got \(error)")
+            }
+        }
+    }
+
+    func testRejectsUnsupportedMediaType() {
+        XCTAssertThrowsError(try ChatMessage.ContentPart.image(data: Data([0x00]), mediaType: "image/tiff"))
+    }
+
+    func testRejectsOversizedImage() {
+        let options = ImageInputOptions(maxBytes: 10)
+
+        XCTAssertThrowsError(try ChatMessage.ContentPart.image(data: Data(base64Encoded: pngBase64)!, options: options)) { error in
+            guard case SwamlError.invalidMedia(let message) = error else {
+                return XCTFail("Expected invalidMedia, got \(error)")
+            }
+            XCTAssertTrue(message.contains("10-byte limit"))
+        }
+    }
+
+    // MARK: - Files and Data URIs
+
+    func testImageFromFile() throws {
+        let url = FileManager.default.temporaryDirectory.appendingPathComponent("swaml-image-\(UUID().uuidString).png")
+        try Data(base64Encoded: pngBase64)!.write(to: url)
+        defer { try? FileManager.default.removeItem(at: url) }
+
+        let part = try ChatMessage.ContentPart.image(contentsOf: url)
+
+        XCTAssertEqual(part, .imageBase64(data: pngBase64, mediaType: "image/png"))
+    }
+
+    func testMissingFileThrows() {
+        let url = URL(fileURLWithPath: "/nonexistent/swaml.png")
+
+        XCTAssertThrowsError(try ChatMessage.ContentPart.image(contentsOf: url))
+    }
+
+    func testRemoteURLPassesThrough() throws {
+        let url = URL(string: "https://example.com/cat.jpg")!
+
+        XCTAssertEqual(try ChatMessage.ContentPart.image(contentsOf: url), .imageURL(url))
+    }
+
+    func testImageFromDataURI() throws {
+        let part = try ChatMessage.ContentPart.image(dataURI: "data:image/png;base64,\(pngBase64)")
+
+        XCTAssertEqual(part, .imageBase64(data: pngBase64, mediaType: "image/png"))
+    }
+
+    func testRejectsMalformedDataURI() {
+        XCTAssertThrowsError(try ChatMessage.ContentPart.image(dataURI: "image/png;base64,abc"))
+        XCTAssertThrowsError(try ChatMessage.ContentPart.image(dataURI: "data:image/png,rawbytes"))
+        XCTAssertThrowsError(try ChatMessage.ContentPart.image(dataURI: "data:image/png;base64,!!!"))
+    }
+}
@@ -127,4 +127,81 @@ final class JSONExtractorTests: XCTestCase {
         XCTAssertNotNil(repaired)
         XCTAssertTrue(repaired!.contains("\"name\""))
     }
+
+    // MARK: - Extraction Strategies
+
+    private let exampleThenAnswer = """
+    For example, a valid reply looks like {"answer": "example"}.
+
+    ```json
+    {"answer": "fenced"}
+    ```
+
+    Final answer: {"answer": "final"}
+    """
+
+    func testPreferFencedChoosesCodeBlock() throws {
+        let result = try JSONExtractor.extractWithDiagnostics(from: exampleThenAnswer)
+
+        XCTAssertEqual(result.json, #"{"answer": "fenced"}"#)
+        XCTAssertEqual(result.source, .codeBlock)
+        XCTAssertEqual(result.candidateCount, 1)
+    }
+
+    func testLastJSONChoosesFinalObject() throws {
+        let result = try JSONExtractor.extractWithDiagnostics(from: exampleThenAnswer, strategy: .lastJSON)
+
+        XCTAssertEqual(result.json, #"{"answer": "final"}"#)
+        XCTAssertEqual(result.source, .embedded)
+        XCTAssertEqual(result.candidateCount, 3)
+    }
+
+    func testFullScanIgnoresFences() throws {
+        let result = try JSONExtractor.extractWithDiagnostics(from: exampleThenAnswer, strategy: .fullScan)
+
+        XCTAssertEqual(result.json, #"{"answer": "example"}"#)
+        XCTAssertEqual(result.offset, exampleThenAnswer.distance(
+            from: exampleThenAnswer.startIndex,
+            to: exampleThenAnswer.range(of: "{")!.lowerBound
+        ))
+    }
+
+    func testFullScanSkipsInvalidBraces() throws {
+        let output = #"Use {placeholders} like this: {"a": 1}"#
+
+        XCTAssertEqual(try JSONExtractor.extract(from: output, strategy: .fullScan), #"{"a": 1}"#)
+    }
+
+    func testEntireOutputDiagnostics() throws {
+        let result = try JSONExtractor.extractWithDiagnostics(from: #"  {"a": 1}  "#, strategy: .lastJSON)
+
+        XCTAssertEqual(result.source, .entireOutput)
+        XCTAssertEqual(result.offset, 0)
+    }
+
+    func testLaterFencedBlockUsedWhenFirstIsInvalid() throws {
+        let output = """
+        ```json
+        not json
+        ```
+        ```json
+        {"ok": true}
+        ```
+        """
+
+        XCTAssertEqual(try JSONExtractor.extract(from: output), #"{"ok": true}"#)
+    }
+
+    func testStrategiesThrowWithoutJSON() {
+        for strategy in [ExtractionStrategy.preferFenced, .lastJSON, .fullScan] {
+            XCTAssertThrowsError(try JSONExtractor.extract(from: "nothing here", strategy: strategy))
+        }
+    }
+
+    func testParseWithDiagnostics() throws {
+        let parsed = try OutputParser.parseWithDiagnostics(exampleThenAnswer, extraction: .lastJSON)
+
+        XCTAssertEqual(parsed.value["answer"]?.stringValue, "final")
+        XCTAssertEqual(parsed.extraction.candidateCount, 3)
+    }
 }
@@ -0,0 +1,252 @@
+import XCTest
+@testable import SWAML
+
+final class JSONSchemaImportTests: XCTestCase {
+
+    private func schema(_ json: String) throws -> [String: Any] {
+        try XCTUnwrap(JSONSerialization.jsonObject(with: Data(json.utf8)) as? [String: Any])
+    }
+
+    // MARK: - Classes
+
+    func testImportObjectAsClass() throws {
+        let tb = TypeBuilder()
+        let root = try tb.importJSONSchema(schema("""
+        {
+            "title": "person",
+            "type": "object",
+            "properties": {
+                "name": {"type": "string", "description": "Full name", "x-alias": "full_name"},
+                "age": {"type": "integer"},
+                "score": {"type": "number"},
+                "active": {"type": "boolean"}
+            },
+            "required": ["name", "age"]
+        }
+        """))
+
+        XCTAssertEqual(root, .reference("Person"))
+
+        let person = try XCTUnwrap(tb.allClassBuilders["Person"])
+        XCTAssertEqual(person.allPropertyNames, ["active", "age", "name", "score"])
+
+        let properties = Dictionary(uniqueKeysWithValues: person.allPropertyBuilders.map { ($0.name, $0) })
+        XCTAssertEqual(properties["name"]?.fieldType, .string)
+        XCTAssertEqual(properties["name"]?.descriptionValue, "Full name")
+        XCTAssertEqual(properties["name"]?.aliasValue, "full_name")
+        XCTAssertEqual(properties["age"]?.fieldType, .int)
+        XCTAssertEqual(properties["score"]?.fieldType, .optional(.float))
+        XCTAssertEqual(properties["active"]?.fieldType, .optional(.bool))
+    }
+
+    func testRootNameOverridesTitle() throws {
+        let tb = TypeBuilder()
+        let root = try tb.importJSONSchema(schema("""
+        {"title": "Ignored", "type": "object", "properties": {"id": {"type": "string"}}}
+        """), rootName: "Ticket")
+
+        XCTAssertEqual(root, .reference("Ticket"))
+        XCTAssertNotNil(tb.allClassBuilders["Ticket"])
+    }
+
+    func testNestedObjectNamedFromParent() throws {
+        let tb = TypeBuilder()
+        try tb.importJSONSchema(schema("""
+        {
+            "type": "object",
+            "properties": {
+                "line_items": {
+                    "type": "array",
+                    "items": {"type": "object", "properties": {"sku": {"type": "string"}}, "required": ["sku"]}
+                }
+            },
+            "required": ["line_items"]
+        }
+        """), rootName: "Order")
+
+        let order = try XCTUnwrap(tb.allClassBuilders["Order"])
+        XCTAssertEqual(order.allPropertyBuilders.first?.fieldType, .list(.reference("OrderLineItemsItem")))
+        XCTAssertNotNil(tb.allClassBuilders["OrderLineItemsItem"])
+    }
+
+    func testAdditionalPropertiesBecomesMap() throws {
+        let tb = TypeBuilder()
+        let root = try tb.importJSONSchema(schema("""
+        {"type": "object", "additionalProperties": {"type": "integer"}}
+        """))
+
+        XCTAssertEqual(root, .map(key: .string, value: .int))
+    }
+
+    // MARK: - Enums
+
+    func testImportStringEnum() throws {
+        let tb = TypeBuilder()
+        try tb.importJSONSchema(schema("""
+        {
+            "type": "object",
+            "properties": {"status": {"type": "string", "enum": ["open", "closed"]}},
+            "required": ["status"]
+        }
+        """), rootName: "Ticket")
+
+        XCTAssertEqual(tb.dynamicEnumValues()["TicketStatus"], ["open", "closed"])
+    }
+
+    func testImportOneOfConstantsAsEnumWithMetadata() throws {
+        let tb = TypeBuilder()
+        let root = try tb.importJSONSchema(schema("""
+        {
+            "title": "Priority",
+            "oneOf": [
+                {"const": "p0", "description": "Drop everything", "x-alias": "urgent"},
+                {"const": "p1"}
+            ]
+        }
+        """))
+
+        XCTAssertEqual(root, .reference("Priority"))
+        let values = try XCTUnwrap(tb.allEnumBuilders["Priority"]?.allValueBuilders)
+        XCTAssertEqual(values.map { $0.name }, ["p0", "p1"])
+        XCTAssertEqual(values[0].descriptionValue, "Drop everything")
+        XCTAssertEqual(values[0].aliasValue, "urgent")
+    }
+
+    func testNullableEnum() throws {
+        let tb = TypeBuilder()
+        let root = try tb.importJSONSchema(schema("""
+        {"enum": ["a", "b", null]}
+        """), rootName: "Choice")
+
+        XCTAssertEqual(root, .optional(.reference("Choice")))
+    }
+
+    // MARK: - Unions and References
+
+    func testOneOfMixedTypesBecomesUnion() throws {
+        let tb = TypeBuilder()
+        let root = try tb.importJSONSchema(schema("""
+        {"anyOf": [{"type": "string"}, {"type": "integer"}, {"type": "null"}]}
+        """))
+
+        XCTAssertEqual(root, .optional(.union([.string, .int])))
+    }
+
+    func testTypeArrayWithNull() throws {
+        let tb = TypeBuilder()
+        let root = try tb.importJSONSchema(schema("""
+        {"type": ["string", "null"]}
+        """))
+
+        XCTAssertEqual(root, .optional(.string))
+    }
+
+    func testDefinitionsKeepTheirNames() throws {
+        let tb = TypeBuilder()
+        try tb.importJSONSchema(schema("""
+        {
+            "type": "object",
+            "properties": {
+                "address": {"$ref": "#/$defs/Address"},
+                "id": {"$ref": "#/definitions/Id"}
+            },
+            "required": ["address", "id"],
+            "$defs": {
+                "Address": {"title": "Other", "type": "object", "properties": {"city": {"type": "string"}}}
+            },
+            "definitions": {
+                "Id": {"type": "string"}
+            }
+        }
+        """), rootName: "Customer")
+
+        let customer = try XCTUnwrap(tb.allClassBuilders["Customer"])
+        let types = customer.allPropertyBuilders.map { $0.fieldType }
+        XCTAssertEqual(types, [.reference("Address"), .string])
+        XCTAssertNotNil(tb.allClassBuilders["Address"])
+        XCTAssertNil(tb.allClassBuilders["Other"])
+    }
+
+    func testRecursiveReference() throws {
+        let tb = TypeBuilder()
+        try tb.importJSONSchema(schema("""
+        {
+            "$ref": "#/$defs/Node",
+            "$defs": {
+                "Node": {
+                    "type": "object",
+                    "properties": {
+                        "children": {"type": "array", "items": {"$ref": "#/$defs/Node"}}
+                    },
+                    "required": ["children"]
+                }
+            }
+        }
+        """))
+
+        let node = try XCTUnwrap(tb.allClassBuilders["Node"])
+        XCTAssertEqual(node.allPropertyBuilders.first?.fieldType, .list(.reference("Node")))
+    }
+
+    func testConstLiterals() throws {
+        let tb = TypeBuilder()
+
+        XCTAssertEqual(try tb.importJSONSchema(schema(#"{"const": "x"}"#)), .literalString("x"))
+        XCTAssertEqual(try tb.importJSONSchema(schema(#"{"const": 3}"#)), .literalInt(3))
+        XCTAssertEqual(try tb.importJSONSchema(schema(#"{"const": true}"#)), .literalBool(true))
+    }
+
+    // MARK: - Errors
+
+    func testUnresolvedReferenceThrows() throws {
+        let tb = TypeBuilder()
+
+        XCTAssertThrowsError(try tb.importJSONSchema(schema(#"{"$ref": "#/$defs/Missing"}"#))) { error in
+            guard case TypeBuilderError.invalidSchema = error else {
+                return XCTFail("Expected invalidSchema, got \(error)")
+            }
+        }
+    }
+
+    func testDuplicateTitlesThrow() throws {
+        let tb = TypeBuilder()
+
+        XCTAssertThrowsError(try tb.importJSONSchema(schema("""
+        {
+            "type": "object",
+            "properties": {
+                "billing": {"title": "Address", "type": "object", "properties": {"iban": {"type": "string"}}},
+                "shipping": {"title": "Address", "type": "object", "properties": {"city": {"type": "string"}}}
+            }
+        }
+        """), rootName: "Order")) { error in
+            guard case TypeBuilderError.invalidSchema(let message) = error else {
+                return XCTFail("Expected invalidSchema, got \(error)")
+            }
+            XCTAssertTrue(message.contains("Address"))
+        }
+    }
+
+    func testTitleMatchingExistingTypeThrows() throws {
+        let tb = TypeBuilder()
+        tb.addEnum("Status").addValue("open")
+
+        XCTAssertThrowsError(try tb.importJSONSchema(schema("""
+        {"title": "Status", "type": "object", "properties": {"code": {"type": "integer"}}}
+        """)))
+        XCTAssertNil(tb.allClassBuilders["Status"])
+    }
+
+    func testArrayWithoutItemsThrows() throws {
+        let tb = TypeBuilder()
+
+        XCTAssertThrowsError(try tb.importJSONSchema(schema(#"{"type": "array"}"#)))
+    }
+
+    func testImportFromData() throws {
+        let tb = TypeBuilder()
+        let data = Data(#"{"type": "object", "properties": {"a": {"type": "string"}}}"#.utf8)
+
+        XCTAssertEqual(try tb.importJSONSchema(data, rootName: "Thing"), .reference("Thing"))
+    }
+}
@@ -244,4 +244,68 @@ final class JSONSchemaTests: XCTestCase {
         let usersSchema = properties?["users"] as? [String: Any]
         XCTAssertEqual(usersSchema?["type"] as? String, "array")
     }
+
+    // MARK: - TypeBuilder Export
+
+    func testDocumentIncludesReferencedDynamicTypes() {
+        let tb = TypeBuilder()
+        tb.addEnum("Status").addValue("open")
+        let item = tb.addClass("Item")
+        item.addProperty("status", .reference("Status"))
+        let order = tb.addClass("Order")
+        order.addProperty("items", .list(.reference("Item")))
+
+        let doc = tb.jsonSchemaDocument(for: .reference("Order"))
+
+        XCTAssertEqual(doc["$ref"] as? String, "#/$defs/Order")
+        let defs = doc["$defs"] as? [String: Any]
+        XCTAssertEqual(Set(defs?.keys.map { $0 } ?? []), ["Order", "Item", "Status"])
+        let status = defs?["Status"] as? [String: Any]
+        XCTAssertEqual(status?["enum"] as? [String], ["open"])
+    }
+
+    func testDocumentLeavesUnknownReferences() {
+        let tb = TypeBuilder()
+
+        let doc = tb.jsonSchemaDocument(for: .reference("Static"))
+
+        XCTAssertEqual(doc["$ref"] as? String, "#/$defs/Static")
+        XCTAssertNil(doc["$defs"])
+    }
+
+    func testDocumentHandlesRecursiveTypes() {
+        let tb = TypeBuilder()
+        tb.addClass("Node").addProperty("children", .list(.reference("Node")))
+
+        let doc = tb.jsonSchemaDocument(for: .reference("Node"))
+
+        let defs = doc["$defs"] as? [String: Any]
+        XCTAssertEqual(defs?.count, 1)
+    }
+
+    func testRuntimeOutputJSONSchema() {
+        let runtime = SwamlRuntime(clientRegistry: ClientRegistry())
+        let tb = TypeBuilder()
+        tb.addEnum("Category").addValue("tech")
+        tb.addClass("Tag").addProperty("label", .string)
+
+        let schema = JSONSchema.object()
+            .property("category", .ref("Category"))
+            .property("tags", .array(items: .ref("Tag")))
+            .build()
+
+        let doc = runtime.outputJSONSchema(schema, typeBuilder: tb)
+
+        let properties = doc?["properties"] as? [String: Any]
+        let category = properties?["category"] as? [String: Any]
+        XCTAssertEqual(category?["enum"] as? [String], ["tech"])
+        let defs = doc?["$defs"] as? [String: Any]
+        XCTAssertNotNil(defs?["Tag"])
+    }
+
+    func testRuntimeOutputJSONSchemaWithoutSchema() {
+        let runtime = SwamlRuntime(clientRegistry: ClientRegistry())
+
+        XCTAssertNil(runtime.outputJSONSchema(nil))
+    }
 }
@@ -0,0 +1,173 @@
+import XCTest
+@testable import SWAML
+
+final class JsonishStreamParserTests: XCTestCase {
+
+    private func decode(_ json: String?) throws -> Any {
+        let text = try XCTUnwrap(json)
+        return try JSONSerialization.jsonObject(with: Data(text.utf8))
+    }
+
+    // MARK: - Partial Snapshots
+
+    func testNoJSONYet() {
+        var parser = JsonishStreamParser()
+        parser.append("Sure, here is the result: ")
+
+        XCTAssertNil(parser.currentJSON())
+    }
+
+    func testPartialObjectIsClosed() throws {
+        var parser = JsonishStreamParser()
+        parser.append(#"{"name": "Al"#)
+
+        let object = try decode(parser.currentJSON()) as? [String: Any]
+        XCTAssertEqual(object?["name"] as? String, "Al")
+    }
+
+    func testDanglingKeyIsDropped() throws {
+        var parser = JsonishStreamParser()
+        parser.append(#"{"name": "Alice", "ag"#)
+
+        let object = try decode(parser.currentJSON()) as? [String: Any]
+        XCTAssertEqual(object?.count, 1)
+        XCTAssertEqual(object?["name"] as? String, "Alice")
+    }
+
+    func testDanglingCommaInArray() throws {
+        var parser = JsonishStreamParser()
+        parser.append("[1, 2,")
+
+        XCTAssertEqual(try decode(parser.currentJSON()) as? [Int], [1, 2])
+    }
+
+    func testIncompleteLiteralFallsBackToLastValue() throws {
+        var parser = JsonishStreamParser()
+        parser.append(#"{"a": 1, "b": tr"#)
+
+        let object = try decode(parser.currentJSON()) as? [String: Any]
+        XCTAssertEqual(object?["a"] as? Int, 1)
+        XCTAssertNil(object?["b"])
+    }
+
+    func testIncompleteEscapeIsDropped() throws {
+        var parser = JsonishStreamParser()
+        parser.append(#"{"text": "caf\u00"#)
+
+        let object = try decode(parser.currentJSON()) as? [String: Any]
+        XCTAssertEqual(object?["text"] as? String, "caf")
+    }
+
+    func testNestedStructures() throws {
+        var parser = JsonishStreamParser()
+        parser.append(#"{"users": [{"name": "A"}, {"name": "B", "tags": ["x""#)
+
+        let object = try decode(parser.currentJSON()) as? [String: Any]
+        let users = object?["users"] as? [[String: Any]]
+        XCTAssertEqual(users?.count, 2)
+        XCTAssertEqual(users?[1]["tags"] as? [String], ["x"])
+    }
+
+    // MARK: - Completion
+
+    func testChunkedStreamMatchesBatchParse() throws {
+        let full = """
+        Here you go:
+        ```json
+        {"title": "Report", "items": [{"id": 1, "ok": true}, {"id": 2, "ok": false}], "note": "a \\"quoted\\" word"}
+        ```
+        """
+
+        var parser = JsonishStreamParser()
+        for char in full {
+            parser.append(String(char))
+        }
+
+        XCTAssertTrue(parser.isComplete)
+        let streamed = try decode(parser.finish()) as? NSDictionary
+        let batch = try decode(JsonishParser.parse(full)) as? NSDictionary
+        XCTAssertEqual(streamed, batch)
+    }
+
+    func testTrailingTextIgnoredAfterRoot() throws {
+        var parser = JsonishStreamParser()
+        parser.append(#"{"a": 1}"#)
+        parser.append(" and some {more} text")
+
+        XCTAssertEqual(try parser.finish(), #"{"a": 1}"#)
+    }
+
+    func testLenientSyntaxFallsBackToJsonishParser() throws {
+        var parser = JsonishStreamParser()
+        parser.append("{name: 'Alice', ")
+        parser.append("age: 30,}")
+
+        let object = try decode(parser.finish()) as? [String: Any]
+        XCTAssertEqual(object?["name"] as? String, "Alice")
+        XCTAssertEqual(object?["age"] as? Int, 30)
+    }
+
+    func testFinishWithoutJSONThrows() {
+        var parser = JsonishStreamParser()
+        parser.append("No JSON here")
+
+        XCTAssertThrowsError(try parser.finish())
+    }
+
+    // MARK: - Large Outputs
+
+    private func largeOutputChunks(items: Int) -> [String] {
+        let item = #"{"id": 12345, "label": "item label with some text", "values": [1, 2, 3]}"#
+        let full = "[" + Array(repeating: item, count: items).joined(separator: ", ") + "]"
+
+        var chunks: [String] = []
+        var index = full.startIndex
+        while index < full.endIndex {
+            let end = full.index(index, offsetBy: 256, limitedBy: full.endIndex) ?? full.endIndex
+            chunks.append(String(full[index..<end]))
+            index = end
+        }
+        return chunks
+    }
+
+    func testLargeStreamedOutput() throws {
+        let chunks = largeOutputChunks(items: 3000)
+
+        var parser = JsonishStreamParser()
+        for chunk in chunks {
+            parser.append(chunk)
+        }
+
+        XCTAssertGreaterThan(parser.buffer.utf8.count, 200_000)
+        XCTAssertEqual((try decode(parser.finish()) as? [Any])?.count, 3000)
+    }
+
+    // MARK: - Benchmarks
+
+    /// Appending a 1MB stream in 256-byte chunks. Compare with `testReparsePerChunkPerformance`.
+    func testAppendPerformance() {
+        let chunks = largeOutputChunks(items: 12_000)
+
+        measure {
+            var parser = JsonishStreamParser()
+            for chunk in chunks {
+                parser.append(chunk)
+            }
+            XCTAssertTrue(parser.isComplete)
+        }
+    }
+
+    /// The quadratic baseline: re-parsing the accumulated output on every chunk. Uses a
+    /// stream of about 80KB to keep the run short.
+    func testReparsePerChunkPerformance() {
+        let chunks = largeOutputChunks(items: 1000)
+
+        measure {
+            var accumulated = ""
+            for chunk in chunks {
+                accumulated += chunk
+                _ = try? JsonishParser.parseStreaming(accumulated, isDone: false)
+            }
+        }
+    }
+}
@@ -0,0 +1,59 @@
+import XCTest
+@testable import SWAML
+
+final class ModelCapabilitiesTests: XCTestCase {
+
+    // MARK: - Image Input
+
+    func testKnownVisionModels() {
+        XCTAssertEqual(ModelCapabilities.supportsImages("gpt-4o-mini"), true)
+        XCTAssertEqual(ModelCapabilities.supportsImages("openai/gpt-4-turbo"), true)
+        XCTAssertEqual(ModelCapabilities.supportsImages("claude-3-5-sonnet-latest"), true)
+        XCTAssertEqual(ModelCapabilities.supportsImages("anthropic/claude-sonnet-4"), true)
+        XCTAssertEqual(ModelCapabilities.supportsImages("google/gemini-2.0-flash"), true)
+        XCTAssertEqual(ModelCapabilities.supportsImages("gpt-4-vision-preview"), true)
+        XCTAssertEqual(ModelCapabilities.supportsImages("gpt-4.5-preview"), true)
+        XCTAssertEqual(ModelCapabilities.supportsImages("gpt-4.1-mini"), true)
+        XCTAssertEqual(ModelCapabilities.supportsImages("o1-2024-12-17"), true)
+        XCTAssertEqual(ModelCapabilities.supportsImages("mistralai/mistral-small-3.1-24b-instruct"), true)
+    }
+
+    func testKnownTextOnlyModels() {
+        XCTAssertEqual(ModelCapabilities.supportsImages("gpt-3.5-turbo"), false)
+        XCTAssertEqual(ModelCapabilities.supportsImages("gpt-4-0613"), false)
+        XCTAssertEqual(ModelCapabilities.supportsImages("o3-mini"), false)
+        XCTAssertEqual(ModelCapabilities.supportsImages("o1-preview"), false)
+        XCTAssertEqual(ModelCapabilities.supportsImages("o1-mini"), false)
+        XCTAssertEqual(ModelCapabilities.supportsImages("gpt-4-turbo-preview"), false)
+        XCTAssertEqual(ModelCapabilities.supportsImages("claude-2.1"), false)
+    }
+
+    func testUnknownModel() {
+        XCTAssertNil(ModelCapabilities.supportsImages("my-finetune"))
+        XCTAssertNil(ModelCapabilities.supportsImages("gpt-4"))
+        XCTAssertNil(ModelCapabilities.supportsImages("mistral-small-latest"))
+    }
+
+    // MARK: - Runtime
+
+    func testRuntimeRejectsImagesForTextOnlyModel() async {
+        let registry = ClientRegistry()
+        await registry.register(name: "legacy", provider: .openAI(apiKey: "key"), model: "gpt-3.5-turbo")
+        let runtime = SwamlRuntime(clientRegistry: registry)
+
+        do {
+            _ = try await runtime.callFunction(
+                "DescribeImage",
+                args: [:],
+                prompt: "Describe this image.",
+                images: [.imageURL(URL(string: "https://example.com/cat.jpg")!)]
+            )
+            XCTFail("Expected invalidFunctionCall")
+        } catch SwamlError.invalidFunctionCall(let name, let reason) {
+            XCTAssertEqual(name, "DescribeImage")
+            XCTAssertTrue(reason.contains("gpt-3.5-turbo"))
+        } catch {
+            XCTFail("Unexpected error: \(error)")
+        }
+    }
+}
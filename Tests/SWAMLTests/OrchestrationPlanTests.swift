@@ -0,0 +1,103 @@
+import XCTest
+@testable import SWAML
+
+final class OrchestrationPlanTests: XCTestCase {
+
+    private func makeRuntime() async -> SwamlRuntime {
+        let registry = ClientRegistry()
+        await registry.register(
+            name: "default",
+            provider: .openAI(apiKey: "key"),
+            model: "gpt-4o",
+            retryPolicy: RetryPolicy(maxRetries: 2, retryableStatusCodes: [429, 503]),
+            defaultTemperature: 0.5,
+            httpConfig: HTTPConfig(totalTimeout: 30),
+            isDefault: true
+        )
+        await registry.register(name: "claude", provider: .anthropic(apiKey: "key"), model: "claude-sonnet-4-20250514")
+        return SwamlRuntime(clientRegistry: registry, concurrencyLimits: ["Extract": 3])
+    }
+
+    // MARK: - Single Client
+
+    func testDefaultClientPlan() async throws {
+        let runtime = await makeRuntime()
+        await runtime.addPostProcessor(OutputPostProcessor("scrub", functions: "Ext*") { $0 })
+
+        let plan = try await runtime.orchestrationPlan(for: "Extract")
+
+        XCTAssertEqual(plan.strategy, .single)
+        XCTAssertEqual(plan.concurrencyLimit, 3)
+        XCTAssertEqual(plan.postProcessors, ["scrub"])
+        XCTAssertEqual(plan.nodes, [
+            OrchestrationPlan.Node(
+                client: "default",
+                provider: "openai",
+                model: "gpt-4o",
+                temperature: 0.5,
+                maxTokens: nil,
+                seed: nil,
+                maxRetries: 2,
+                retryableStatusCodes: [429, 503],
+                timeout: 30
+            )
+        ])
+    }
+
+    func testContextOverridesAndShorthand() async throws {
+        let runtime = await makeRuntime()
+        let ctx = RuntimeContext.builder()
+            .client("openai/gpt-4o-mini")
+            .temperature(0.9)
+            .timeout(5)
+            .validationRetries(1)
+            .build()
+
+        let plan = try await runtime.orchestrationPlan(for: "Classify", ctx: ctx)
+
+        XCTAssertEqual(plan.nodes.first?.model, "gpt-4o-mini")
+        XCTAssertEqual(plan.nodes.first?.temperature, 0.9)
+        XCTAssertEqual(plan.nodes.first?.timeout, 5)
+        XCTAssertEqual(plan.validationRetries, 1)
+        XCTAssertNil(plan.concurrencyLimit)
+    }
+
+    // MARK: - Race
+
+    func testRacePlan() async throws {
+        let runtime = await makeRuntime()
+        let ctx = RuntimeContext.builder()
+            .race(["default", "claude"])
+            .build()
+
+        let plan = try await runtime.orchestrationPlan(for: "Classify", ctx: ctx)
+
+        XCTAssertEqual(plan.strategy, .race)
+        XCTAssertEqual(plan.nodes.map(\.provider), ["openai", "anthropic"])
+    }
+
+    func testUnknownClientThrows() async {
+        let runtime = await makeRuntime()
+
+        do {
+            _ = try await runtime.orchestrationPlan(for: "Classify", ctx: .withClient("missing"))
+            XCTFail("Expected clientNotFound")
+        } catch SwamlError.clientNotFound(let name) {
+            XCTAssertEqual(name, "missing")
+        } catch {
+            XCTFail("Unexpected error: \(error)")
+        }
+    }
+
+    // MARK: - Encoding
+
+    func testPlanRoundTripsThroughJSON() async throws {
+        let runtime = await makeRuntime()
+        let plan = try await runtime.orchestrationPlan(for: "Extract")
+
+        let data = try JSONEncoder().encode(plan)
+        let decoded = try JSONDecoder().decode(OrchestrationPlan.self, from: data)
+
+        XCTAssertEqual(decoded, plan)
+    }
+}
@@ -144,4 +144,56 @@ final class OutputParserTests: XCTestCase {
 
         XCTAssertThrowsError(try OutputParser.parse(json, type: TestPerson.self))
     }
+
+    // MARK: - Coercion Options
+
+    func testRejectUnknownFields() {
+        let schema = JSONSchema.object()
+            .property("name", .string)
+            .build()
+        let json = #"{"name": "Alice", "extra": 1}"#
+
+        XCTAssertNoThrow(try OutputParser.parseToValue(json, schema: schema))
+        XCTAssertThrowsError(try OutputParser.parseToValue(json, schema: schema, options: .strict)) { error in
+            XCTAssertEqual((error as? SwamlError)?.errorDescription?.contains("extra"), true)
+        }
+    }
+
+    func testRejectUnknownFieldsAllowsAdditionalProperties() throws {
+        let schema = JSONSchema.object(properties: [:], required: [], additionalProperties: .integer)
+
+        let value = try OutputParser.parseToValue(#"{"a": 1}"#, schema: schema, options: .strict)
+
+        XCTAssertEqual(value["a"]?.intValue, 1)
+    }
+
+    func testEnumExactMatchByDefault() {
+        let schema = JSONSchema.object()
+            .property("status", .enum(values: ["Active", "Inactive"]))
+            .build()
+
+        XCTAssertThrowsError(try OutputParser.parseToValue(#"{"status": "active"}"#, schema: schema))
+    }
+
+    func testCaseInsensitiveEnums() throws {
+        let schema = JSONSchema.object()
+            .property("status", .enum(values: ["Active", "Inactive"]))
+            .build()
+        let options = CoercionOptions(caseInsensitiveEnums: true)
+
+        let value = try OutputParser.parseToValue(#"{"status": " active "}"#, schema: schema, options: options)
+
+        XCTAssertEqual(value["status"]?.stringValue, "Active")
+    }
+
+    func testStrictRejectsStringNumbersInTypedParse() {
+        let schema = JSONSchema.object()
+            .property("name", .string)
+            .property("age", .integer)
+            .build()
+        let json = #"{"name": "Alice", "age": "30"}"#
+
+        XCTAssertNoThrow(try OutputParser.parse(json, schema: schema, type: TestPerson.self))
+        XCTAssertThrowsError(try OutputParser.parse(json, schema: schema, type: TestPerson.self, options: .strict))
+    }
 }
@@ -0,0 +1,73 @@
+import XCTest
+@testable import SWAML
+
+final class OutputPostProcessorTests: XCTestCase {
+
+    // MARK: - Pattern Matching
+
+    func testPatternMatching() {
+        let identity: @Sendable (SwamlValue) throws -> SwamlValue = { $0 }
+
+        XCTAssertTrue(OutputPostProcessor("all", process: identity).matches("Anything"))
+        XCTAssertTrue(OutputPostProcessor("exact", functions: "ExtractResume", process: identity).matches("ExtractResume"))
+        XCTAssertFalse(OutputPostProcessor("exact", functions: "ExtractResume", process: identity).matches("ExtractResumes"))
+
+        let prefix = OutputPostProcessor("prefix", functions: "Extract*", process: identity)
+        XCTAssertTrue(prefix.matches("Extract"))
+        XCTAssertTrue(prefix.matches("ExtractInvoice"))
+        XCTAssertFalse(prefix.matches("Classify"))
+
+        let middle = OutputPostProcessor("middle", functions: "*Invoice?", process: identity)
+        XCTAssertTrue(middle.matches("ParseInvoices"))
+        XCTAssertFalse(middle.matches("ParseInvoice"))
+    }
+
+    // MARK: - Processing
+
+    func testValueProcessor() throws {
+        let processor = OutputPostProcessor("redact") { value in
+            guard case .map(var fields) = value else { return value }
+            fields["email"] = .string("[REDACTED]")
+            return .map(fields)
+        }
+
+        let result = try processor.apply(to: .map(["name": .string("Ada"), "email": .string("ada@example.com")]))
+
+        XCTAssertEqual(result["email"]?.stringValue, "[REDACTED]")
+        XCTAssertEqual(result["name"]?.stringValue, "Ada")
+    }
+
+    func testTypedProcessor() throws {
+        struct Measurement: Codable {
+            var value: Double
+            var unit: String
+        }
+
+        let processor = OutputPostProcessor("to-meters", as: Measurement.self) { measurement in
+            guard measurement.unit == "cm" else { return measurement }
+            return Measurement(value: measurement.value / 100, unit: "m")
+        }
+
+        let result = try processor.apply(to: .map(["value": .float(250), "unit": .string("cm")]))
+
+        XCTAssertEqual(result["unit"]?.stringValue, "m")
+        XCTAssertEqual(result["value"]?.doubleValue ?? 0, 2.5, accuracy: 0.0001)
+    }
+
+    // MARK: - Runtime Registration
+
+    func testRuntimeAddAndRemove() async {
+        let runtime = SwamlRuntime(clientRegistry: ClientRegistry())
+
+        await runtime.addPostProcessor(OutputPostProcessor("a") { $0 })
+        await runtime.addPostProcessor(OutputPostProcessor("b", functions: "Extract*") { $0 })
+        await runtime.addPostProcessor(OutputPostProcessor("a", functions: "Classify") { $0 })
+
+        var names = await runtime.postProcessors.map(\.name)
+        XCTAssertEqual(names, ["a", "b", "a"])
+
+        await runtime.removePostProcessors(named: "a")
+        names = await runtime.postProcessors.map(\.name)
+        XCTAssertEqual(names, ["b"])
+    }
+}
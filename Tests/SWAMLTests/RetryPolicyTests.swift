@@ -107,6 +107,14 @@ final class RetryPolicyTests: XCTestCase {
         XCTAssertFalse(policy.shouldRetry(error: error, attempt: 3)) // Max reached
     }
 
+    func testShouldNotRetryCancelledRequests() {
+        let policy = RetryPolicy.standard
+
+        XCTAssertFalse(policy.shouldRetry(error: CancellationError(), attempt: 0))
+        XCTAssertFalse(policy.shouldRetry(error: URLError(.cancelled), attempt: 0))
+        XCTAssertTrue(policy.shouldRetry(error: URLError(.timedOut), attempt: 0))
+    }
+
     // MARK: - Preset Policies
 
     func testNonePolicy() {
@@ -54,6 +54,15 @@ final class RuntimeContextTests: XCTestCase {
         XCTAssertTrue(ctx.tags.isEmpty)
     }
 
+    func testInstanceWithClientKeepsSettings() {
+        let base = RuntimeContext(tags: ["team": "search"], clientName: "default", temperature: 0.3)
+        let ctx = base.withClient("openai/gpt-4o-mini")
+
+        XCTAssertEqual(ctx.clientName, "openai/gpt-4o-mini")
+        XCTAssertEqual(ctx.temperature, 0.3)
+        XCTAssertEqual(ctx.tags["team"], "search")
+    }
+
     func testWithTags() {
         let ctx = RuntimeContext.withTags(["request_id": "abc123"])
 
@@ -219,6 +228,34 @@ final class RuntimeContextTests: XCTestCase {
         XCTAssertEqual(child.timeout, 30.0)
     }
 
+    func testChildContextPreservesCoercion() {
+        let parent = RuntimeContext.builder()
+            .coercion(.strict)
+            .build()
+        let child = parent.child()
+
+        XCTAssertEqual(child.coercion, .strict)
+    }
+
+    func testChildContextPreservesRaceClients() {
+        let parent = RuntimeContext.builder()
+            .race(["fast", "openai/gpt-4o-mini"])
+            .build()
+        let child = parent.child(temperature: 0.1)
+
+        XCTAssertEqual(child.raceClients, ["fast", "openai/gpt-4o-mini"])
+    }
+
+    func testChildContextPreservesValidationRetries() {
+        let parent = RuntimeContext.builder()
+            .validationRetries(2)
+            .build()
+
+        XCTAssertEqual(parent.validationRetries, 2)
+        XCTAssertEqual(parent.child(clientName: "other").validationRetries, 2)
+        XCTAssertEqual(RuntimeContext.default.validationRetries, 0)
+    }
+
     // MARK: - Multiple Generations
 
     func testMultipleChildGenerations() {
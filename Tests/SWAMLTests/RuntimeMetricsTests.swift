@@ -0,0 +1,148 @@
+import XCTest
+@testable import SWAML
+
+final class RuntimeMetricsTests: XCTestCase {
+
+    // MARK: - Failure Categories
+
+    func testFailureCategories() {
+        XCTAssertEqual(FailureCategory(error: SwamlError.networkError("down")), .network)
+        XCTAssertEqual(FailureCategory(error: SwamlError.requestTimeout(seconds: 5)), .timeout)
+        XCTAssertEqual(FailureCategory(error: SwamlError.apiError(statusCode: 429, message: "")), .rateLimited)
+        XCTAssertEqual(FailureCategory(error: SwamlError.apiError(statusCode: 500, message: "")), .api)
+        XCTAssertEqual(FailureCategory(error: SwamlError.contextWindowExceeded(tokens: 10, limit: 5)), .contextWindow)
+        XCTAssertEqual(FailureCategory(error: SwamlError.jsonExtractionError("none")), .parse)
+        XCTAssertEqual(FailureCategory(error: SwamlError.typeCoercionError(expected: "int", actual: "string")), .validation)
+        XCTAssertEqual(FailureCategory(error: SwamlError.clientNotFound("x")), .configuration)
+        XCTAssertEqual(FailureCategory(error: NSError(domain: "Custom", code: 1)), .other)
+    }
+
+    func testTransportErrorCategories() {
+        XCTAssertEqual(FailureCategory(error: URLError(.timedOut)), .timeout)
+        XCTAssertEqual(FailureCategory(error: URLError(.notConnectedToInternet)), .network)
+        XCTAssertEqual(FailureCategory(error: URLError(.cannotConnectToHost)), .network)
+        XCTAssertEqual(FailureCategory(error: URLError(.networkConnectionLost)), .network)
+        XCTAssertEqual(FailureCategory(error: URLError(.badURL)), .network)
+    }
+
+    func testCancellationIsNotATimeout() {
+        XCTAssertEqual(FailureCategory(error: CancellationError()), .cancelled)
+        XCTAssertEqual(FailureCategory(error: URLError(.cancelled)), .cancelled)
+
+        let metrics = RuntimeMetrics()
+        metrics.recordCall(function: "Extract", duration: 1, error: CancellationError())
+
+        XCTAssertEqual(metrics.failureCount(category: .timeout), 0)
+        XCTAssertTrue(metrics.prometheusText().contains("swaml_failures_total{category=\"cancelled\",function=\"Extract\"} 1"))
+    }
+
+    // MARK: - Recording
+
+    func testRecordCalls() {
+        let metrics = RuntimeMetrics()
+        metrics.recordCall(function: "Extract", duration: 0.2, error: nil)
+        metrics.recordCall(function: "Extract", duration: 0.4, error: SwamlError.parseError("bad"))
+        metrics.recordCall(function: "Classify", duration: 1.0, error: nil)
+
+        XCTAssertEqual(metrics.callCount(), 3)
+        XCTAssertEqual(metrics.callCount(function: "Extract"), 2)
+        XCTAssertEqual(metrics.failureCount(), 1)
+        XCTAssertEqual(metrics.failureCount(function: "Extract", category: .parse), 1)
+        XCTAssertEqual(metrics.failureCount(category: .network), 0)
+        XCTAssertEqual(metrics.totalLatency(function: "Extract"), 0.6, accuracy: 0.0001)
+    }
+
+    func testRecordUsageAndRetries() {
+        let metrics = RuntimeMetrics()
+        metrics.recordUsage(model: "gpt-4o", usage: .init(promptTokens: 100, completionTokens: 20, totalTokens: 120))
+        metrics.recordUsage(model: "gpt-4o", usage: .init(promptTokens: 50, completionTokens: 10, totalTokens: 60))
+        metrics.recordRetry(function: "Extract")
+
+        XCTAssertEqual(metrics.promptTokens(model: "gpt-4o"), 150)
+        XCTAssertEqual(metrics.completionTokens(), 30)
+        XCTAssertEqual(metrics.retryCount(function: "Extract"), 1)
+
+        metrics.reset()
+        XCTAssertEqual(metrics.promptTokens(), 0)
+        XCTAssertEqual(metrics.retryCount(), 0)
+    }
+
+    func testRecordValidationRetries() {
+        let metrics = RuntimeMetrics()
+        metrics.recordValidationRetry(function: "Extract")
+        metrics.recordValidationRetry(function: "Extract")
+
+        XCTAssertEqual(metrics.validationRetryCount(function: "Extract"), 2)
+        XCTAssertEqual(metrics.retryCount(function: "Extract"), 0)
+        XCTAssertTrue(metrics.prometheusText().contains("swaml_validation_retries_total{function=\"Extract\"} 2"))
+    }
+
+    // MARK: - Prometheus Export
+
+    func testPrometheusText() {
+        let metrics = RuntimeMetrics()
+        metrics.recordCall(function: "Extract", duration: 0.3, error: nil)
+        metrics.recordCall(function: "Extract", duration: 3, error: SwamlError.requestTimeout(seconds: 3))
+        metrics.recordUsage(model: "gpt-4o", usage: .init(promptTokens: 10, completionTokens: 5, totalTokens: 15))
+
+        let text = metrics.prometheusText()
+
+        XCTAssertTrue(text.contains("# TYPE swaml_calls_total counter"))
+        XCTAssertTrue(text.contains("swaml_calls_total{function=\"Extract\",outcome=\"success\"} 1"))
+        XCTAssertTrue(text.contains("swaml_calls_total{function=\"Extract\",outcome=\"failure\"} 1"))
+        XCTAssertTrue(text.contains("swaml_failures_total{category=\"timeout\",function=\"Extract\"} 1"))
+        XCTAssertTrue(text.contains("swaml_call_duration_seconds_bucket{function=\"Extract\",le=\"0.5\"} 1"))
+        XCTAssertTrue(text.contains("swaml_call_duration_seconds_bucket{function=\"Extract\",le=\"5\"} 2"))
+        XCTAssertTrue(text.contains("swaml_call_duration_seconds_bucket{function=\"Extract\",le=\"+Inf\"} 2"))
+        XCTAssertTrue(text.contains("swaml_call_duration_seconds_count{function=\"Extract\"} 2"))
+        XCTAssertTrue(text.contains("swaml_tokens_total{model=\"gpt-4o\",type=\"prompt\"} 10"))
+    }
+
+    func testPrometheusEscapesLabels() {
+        let metrics = RuntimeMetrics()
+        metrics.recordRetry(function: "say \"hi\"")
+
+        XCTAssertTrue(metrics.prometheusText().contains("swaml_retries_total{function=\"say \\\"hi\\\"\"} 1"))
+    }
+
+    func testRaceWins() {
+        let metrics = RuntimeMetrics()
+        metrics.recordRaceWin(function: "Classify", client: "fast")
+        metrics.recordRaceWin(function: "Classify", client: "fast")
+        metrics.recordRaceWin(function: "Extract", client: "smart")
+
+        XCTAssertEqual(metrics.raceWinCount(client: "fast"), 2)
+        XCTAssertEqual(metrics.raceWinCount(client: "smart", function: "Classify"), 0)
+        XCTAssertTrue(metrics.prometheusText().contains("swaml_race_wins_total{client=\"fast\",function=\"Classify\"} 2"))
+    }
+
+    // MARK: - Runtime
+
+    func testRuntimeRecordsFailedCall() async {
+        let registry = ClientRegistry()
+        let runtime = SwamlRuntime(clientRegistry: registry)
+
+        _ = try? await runtime.callFunction("Extract", args: [:], prompt: "Hi")
+
+        XCTAssertEqual(runtime.metrics.callCount(function: "Extract"), 1)
+        XCTAssertEqual(runtime.metrics.failureCount(function: "Extract", category: .configuration), 1)
+    }
+
+    func testRaceFailsWhenEveryClientFails() async {
+        let runtime = SwamlRuntime(clientRegistry: ClientRegistry())
+        let ctx = RuntimeContext.builder()
+            .race(["missing-a", "missing-b"])
+            .build()
+
+        do {
+            _ = try await runtime.callFunction("Classify", args: [:], prompt: "Hi", ctx: ctx)
+            XCTFail("Expected clientNotFound")
+        } catch SwamlError.clientNotFound(let name) {
+            XCTAssertTrue(["missing-a", "missing-b"].contains(name))
+        } catch {
+            XCTFail("Unexpected error: \(error)")
+        }
+        XCTAssertEqual(runtime.metrics.raceWinCount(client: "missing-a"), 0)
+        XCTAssertEqual(runtime.metrics.callCount(function: "Classify"), 1)
+    }
+}
@@ -0,0 +1,74 @@
+import XCTest
+@testable import SWAML
+
+final class SamplingSettingsTests: XCTestCase {
+
+    // MARK: - Resolution
+
+    func testCallOverridesClientDefaults() {
+        let config = ClientConfig(
+            name: "default",
+            provider: .openAI(apiKey: "key"),
+            model: "gpt-4o",
+            defaultTemperature: 0.7,
+            defaultMaxTokens: 500
+        )
+
+        let settings = SamplingSettings.resolve(config: config, temperature: 0.2, maxTokens: nil, deterministic: nil)
+
+        XCTAssertEqual(settings.temperature, 0.2)
+        XCTAssertEqual(settings.maxTokens, 500)
+        XCTAssertNil(settings.seed)
+    }
+
+    func testDeterministicModeForcesTemperatureAndSeed() {
+        let config = ClientConfig(
+            name: "default",
+            provider: .openAI(apiKey: "key"),
+            model: "gpt-4o",
+            defaultTemperature: 0.7
+        )
+
+        let settings = SamplingSettings.resolve(
+            config: config,
+            temperature: 1.0,
+            maxTokens: nil,
+            deterministic: DeterministicMode(seed: 42)
+        )
+
+        XCTAssertEqual(settings.temperature, 0)
+        XCTAssertEqual(settings.seed, 42)
+    }
+
+    func testSeedOmittedForAnthropic() {
+        let config = ClientConfig(name: "claude", provider: .anthropic(apiKey: "key"), model: "claude-sonnet-4")
+
+        let settings = SamplingSettings.resolve(config: config, temperature: nil, maxTokens: nil, deterministic: DeterministicMode())
+
+        XCTAssertEqual(settings.temperature, 0)
+        XCTAssertNil(settings.seed)
+    }
+
+    // MARK: - Runtime
+
+    func testRuntimeRecordsEffectiveSettings() async {
+        let registry = ClientRegistry()
+        await registry.register(
+            name: "small",
+            provider: .openAI(apiKey: "key"),
+            model: "gpt-4o",
+            defaultTemperature: 0.9,
+            contextWindow: 10
+        )
+        let runtime = SwamlRuntime(clientRegistry: registry, deterministicMode: DeterministicMode(seed: 7))
+        let ctx = RuntimeContext.builder()
+            .checkContextWindow()
+            .build()
+
+        // Fails at the context-window preflight, after the settings are resolved
+        _ = try? await runtime.callFunction("Summarize", args: [:], prompt: "A long enough prompt", ctx: ctx)
+
+        let settings = await runtime.samplingSettings(for: "Summarize")
+        XCTAssertEqual(settings, SamplingSettings(client: "small", model: "gpt-4o", temperature: 0, maxTokens: nil, seed: 7))
+    }
+}
@@ -0,0 +1,138 @@
+import Foundation
+#if canImport(FoundationNetworking)
+import FoundationNetworking
+#endif
+@testable import SWAML
+
+/// Serves canned OpenAI-style completions so runtime tests run without network access.
+///
+/// Replies are queued per model and served in order. Register the class in `setUp` and
+/// point clients at `StubLLMProtocol.provider`.
+final class StubLLMProtocol: URLProtocol {
+    static let host = "stub.swaml.test"
+
+    static var provider: LLMProvider {
+        .custom(baseURL: URL(string: "https://\(host)/v1")!, apiKey: "test")
+    }
+
+    private struct Reply {
+        let content: String
+        let delay: TimeInterval
+    }
+
+    private static let lock = NSLock()
+    private static var replies: [String: [Reply]] = [:]
+    private static var bodies: [[String: Any]] = []
+
+    /// Queue a completion for the next request to `model`
+    static func enqueue(_ content: String, model: String, delay: TimeInterval = 0) {
+        lock.lock()
+        defer { lock.unlock() }
+        replies[model, default: []].append(Reply(content: content, delay: delay))
+    }
+
+    /// JSON bodies of every request received, in order
+    static var requestBodies: [[String: Any]] {
+        lock.lock()
+        defer { lock.unlock() }
+        return bodies
+    }
+
+    static func reset() {
+        lock.lock()
+        defer { lock.unlock() }
+        replies = [:]
+        bodies = []
+    }
+
+    private static func nextReply(for body: [String: Any]) -> Reply? {
+        lock.lock()
+        defer { lock.unlock() }
+        bodies.append(body)
+        guard let model = body["model"] as? String, var queue = replies[model], !queue.isEmpty else {
+            return nil
+        }
+        let reply = queue.removeFirst()
+        replies[model] = queue
+        return reply
+    }
+
+    // MARK: - URLProtocol
+
+    private let stateLock = NSLock()
+    private var stopped = false
+
+    override class func canInit(with request: URLRequest) -> Bool {
+        request.url?.host == host
+    }
+
+    override class func canonicalRequest(for request: URLRequest) -> URLRequest {
+        request
+    }
+
+    override func startLoading() {
+        let body = (try? JSONSerialization.jsonObject(with: requestBody())) as? [String: Any] ?? [:]
+        let model = body["model"] as? String ?? ""
+
+        guard let reply = Self.nextReply(for: body) else {
+            respond(status: 500, body: Data("No stub reply for \(model)".utf8))
+            return
+        }
+
+        let completion: [String: Any] = [
+            "id": "stub",
+            "object": "chat.completion",
+            "created": 0,
+            "model": model,
+            "choices": [[
+                "index": 0,
+                "message": ["role": "assistant", "content": reply.content],
+                "finish_reason": "stop"
+            ]]
+        ]
+        let data = (try? JSONSerialization.data(withJSONObject: completion)) ?? Data()
+
+        DispatchQueue.global().asyncAfter(deadline: .now() + reply.delay) {
+            self.respond(status: 200, body: data)
+        }
+    }
+
+    override func stopLoading() {
+        stateLock.lock()
+        stopped = true
+        stateLock.unlock()
+    }
+
+    private func respond(status: Int, body: Data) {
+        stateLock.lock()
+        defer { stateLock.unlock() }
+        guard !stopped, let url = request.url,
+              let response = HTTPURLResponse(url: url, statusCode: status, httpVersion: "HTTP/1.1", headerFields: nil) else {
+            return
+        }
+        client?.urlProtocol(self, didReceive: response, cacheStoragePolicy: .notAllowed)
+        client?.urlProtocol(self, didLoad: body)
+        client?.urlProtocolDidFinishLoading(self)
+    }
+
+    /// URLSession moves the body into a stream before it reaches the protocol
+    private func requestBody() -> Data {
+        if let body = request.httpBody {
+            return body
+        }
+        guard let stream = request.httpBodyStream else {
+            return Data()
+        }
+
+        var data = Data()
+        var buffer = [UInt8](repeating: 0, count: 4096)
+        stream.open()
+        defer { stream.close() }
+        while stream.hasBytesAvailable {
+            let count = stream.read(&buffer, maxLength: buffer.count)
+            guard count > 0 else { break }
+            data.append(buffer, count: count)
+        }
+        return data
+    }
+}
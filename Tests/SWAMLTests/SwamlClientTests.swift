@@ -109,6 +109,65 @@ final class SwamlClientTests: XCTestCase {
         XCTAssertTrue(classBuilder.hasProperty("y"))
         XCTAssertTrue(classBuilder.hasProperty("z"))
     }
+
+    // MARK: - Call Options Tests
+
+    func testCallOptionsMerging() {
+        let defaults = SwamlCallOptions(temperature: 0.2, maxTokens: 500, stop: ["END"])
+        let merged = defaults.merging(SwamlCallOptions(temperature: 0.9, topP: 0.5))
+
+        XCTAssertEqual(merged, SwamlCallOptions(temperature: 0.9, maxTokens: 500, topP: 0.5, stop: ["END"]))
+    }
+
+    func testSetDefaultOptions() async {
+        let client = SwamlClient(provider: .openAI(apiKey: "test"))
+        let initial = await client.defaultOptions
+        XCTAssertEqual(initial, SwamlCallOptions())
+
+        await client.setDefaultOptions(SwamlCallOptions(temperature: 0, presencePenalty: 0.5))
+
+        let options = await client.defaultOptions
+        XCTAssertEqual(options.temperature, 0)
+        XCTAssertEqual(options.presencePenalty, 0.5)
+    }
+
+    func testRepairAndBatchUseDefaultOptions() async throws {
+        struct Ticket: SwamlTyped {
+            let status: String
+
+            static var swamlTypeName: String { "Ticket" }
+            static var swamlSchema: JSONSchema {
+                .object(properties: ["status": .string], required: ["status"])
+            }
+        }
+
+        StubLLMProtocol.reset()
+        URLProtocol.registerClass(StubLLMProtocol.self)
+        defer {
+            URLProtocol.unregisterClass(StubLLMProtocol.self)
+            StubLLMProtocol.reset()
+        }
+
+        let client = SwamlClient(llmClient: LLMClient(provider: StubLLMProtocol.provider))
+        await client.setDefaultOptions(SwamlCallOptions(topP: 0.5, stop: ["END"]))
+        StubLLMProtocol.enqueue("not json", model: "fast")
+        StubLLMProtocol.enqueue(#"{"status": "open"}"#, model: "fast")
+        StubLLMProtocol.enqueue(#"{"status": "closed"}"#, model: "fast")
+
+        let repaired = try await client.callWithRepair(model: "fast", prompt: "Hi", returnType: Ticket.self)
+        let batched = try await client.batch(model: "fast", prompts: ["Hi"], returnType: Ticket.self)
+
+        XCTAssertEqual(repaired.status, "open")
+        XCTAssertEqual(try batched.first?.get().status, "closed")
+
+        let bodies = StubLLMProtocol.requestBodies
+        XCTAssertEqual(bodies.count, 3)
+        for body in bodies {
+            XCTAssertEqual(body["top_p"] as? Double, 0.5)
+            XCTAssertEqual(body["stop"] as? [String], ["END"])
+        }
+        XCTAssertEqual(bodies[1]["temperature"] as? Double, 0)
+    }
 }
 
 // MARK: - Schema Generation Tests
@@ -0,0 +1,167 @@
+import XCTest
+@testable import SWAML
+
+final class SwamlRuntimeTests: XCTestCase {
+
+    private struct Ticket: Codable {
+        let status: String
+    }
+
+    private let ticketSchema = JSONSchema.object(
+        properties: ["status": .enum(values: ["open", "closed"])],
+        required: ["status"]
+    )
+
+    override func setUp() {
+        super.setUp()
+        StubLLMProtocol.reset()
+        URLProtocol.registerClass(StubLLMProtocol.self)
+    }
+
+    override func tearDown() {
+        URLProtocol.unregisterClass(StubLLMProtocol.self)
+        StubLLMProtocol.reset()
+        super.tearDown()
+    }
+
+    private func makeRuntime(models: [String] = ["fast"]) async -> SwamlRuntime {
+        let registry = ClientRegistry()
+        for model in models {
+            await registry.register(name: model, provider: StubLLMProtocol.provider, model: model, retryPolicy: .none)
+        }
+        return SwamlRuntime(clientRegistry: registry)
+    }
+
+    // MARK: - Post-Processors
+
+    func testPostProcessorsDoNotChangeHowOutputIsChecked() async throws {
+        let runtime = await makeRuntime()
+        StubLLMProtocol.enqueue(#"{"status": "pending"}"#, model: "fast")
+        StubLLMProtocol.enqueue(#"{"status": "pending"}"#, model: "fast")
+
+        let plain = try await runtime.callFunction(
+            "Triage", args: [:], prompt: "Hi", outputSchema: ticketSchema, outputType: Ticket.self
+        )
+        await runtime.addPostProcessor(OutputPostProcessor("noop") { $0 })
+        let processed = try await runtime.callFunction(
+            "Triage", args: [:], prompt: "Hi", outputSchema: ticketSchema, outputType: Ticket.self
+        )
+
+        XCTAssertEqual(plain.status, "pending")
+        XCTAssertEqual(processed.status, "pending")
+    }
+
+    func testFailingPostProcessorIsNotRetried() async throws {
+        let runtime = await makeRuntime()
+        StubLLMProtocol.enqueue(#"{"status": "open"}"#, model: "fast")
+        await runtime.addPostProcessor(OutputPostProcessor("strict") { _ in
+            throw SwamlError.schemaValidationError("rejected by processor")
+        })
+        let ctx = RuntimeContext.builder()
+            .validationRetries(2)
+            .build()
+
+        do {
+            _ = try await runtime.callFunction("Triage", args: [:], prompt: "Hi", outputSchema: ticketSchema, ctx: ctx)
+            XCTFail("Expected internalError")
+        } catch SwamlError.internalError(let message) {
+            XCTAssertTrue(message.contains("'strict'"))
+        } catch {
+            XCTFail("Unexpected error: \(error)")
+        }
+        XCTAssertEqual(StubLLMProtocol.requestBodies.count, 1)
+        XCTAssertEqual(runtime.metrics.validationRetryCount(function: "Triage"), 0)
+    }
+
+    // MARK: - Racing
+
+    func testRaceWinnerMustDecodeAsOutputType() async throws {
+        let runtime = await makeRuntime(models: ["fast", "slow"])
+        StubLLMProtocol.enqueue(#"{"state": "open"}"#, model: "fast")
+        StubLLMProtocol.enqueue(#"{"status": "closed"}"#, model: "slow", delay: 0.2)
+        let ctx = RuntimeContext.builder()
+            .race(["fast", "slow"])
+            .build()
+
+        let ticket = try await runtime.callFunction("Triage", args: [:], prompt: "Hi", outputType: Ticket.self, ctx: ctx)
+
+        XCTAssertEqual(ticket.status, "closed")
+        XCTAssertEqual(runtime.metrics.raceWinCount(client: "slow"), 1)
+        XCTAssertEqual(runtime.metrics.raceWinCount(client: "fast"), 0)
+    }
+
+    func testRaceLosersAreNotRetried() async throws {
+        let registry = ClientRegistry()
+        let retryPolicy = RetryPolicy(maxRetries: 2, initialDelay: 0.01, jitter: false)
+        await registry.register(name: "fast", provider: StubLLMProtocol.provider, model: "fast", retryPolicy: retryPolicy)
+        await registry.register(name: "slow", provider: StubLLMProtocol.provider, model: "slow", retryPolicy: retryPolicy)
+        let runtime = SwamlRuntime(clientRegistry: registry)
+        StubLLMProtocol.enqueue(#"{"status": "open"}"#, model: "fast")
+        StubLLMProtocol.enqueue(#"{"status": "closed"}"#, model: "slow", delay: 0.5)
+        let ctx = RuntimeContext.builder()
+            .race(["fast", "slow"])
+            .build()
+
+        let ticket = try await runtime.callFunction("Triage", args: [:], prompt: "Hi", outputType: Ticket.self, ctx: ctx)
+        try await Task.sleep(nanoseconds: 100_000_000)
+
+        XCTAssertEqual(ticket.status, "open")
+        XCTAssertEqual(runtime.metrics.retryCount(function: "Triage"), 0)
+        XCTAssertEqual(StubLLMProtocol.requestBodies.count, 2)
+    }
+
+    func testRaceUsesValidationRetries() async throws {
+        let runtime = await makeRuntime(models: ["fast", "slow"])
+        StubLLMProtocol.enqueue("not json", model: "fast")
+        StubLLMProtocol.enqueue("still not json", model: "slow", delay: 0.1)
+        StubLLMProtocol.enqueue(#"{"status": "open"}"#, model: "fast")
+        StubLLMProtocol.enqueue(#"{"status": "closed"}"#, model: "slow", delay: 0.5)
+        let ctx = RuntimeContext.builder()
+            .race(["fast", "slow"])
+            .validationRetries(1)
+            .build()
+
+        let ticket = try await runtime.callFunction("Triage", args: [:], prompt: "Hi", outputType: Ticket.self, ctx: ctx)
+
+        XCTAssertEqual(ticket.status, "open")
+        XCTAssertEqual(runtime.metrics.validationRetryCount(function: "Triage"), 1)
+        let retried = StubLLMProtocol.requestBodies.dropFirst(2)
+        XCTAssertTrue(retried.allSatisfy { ($0["messages"] as? [Any])?.count == 3 })
+    }
+
+    func testRaceWithoutValidationRetriesThrowsOutputError() async {
+        let runtime = await makeRuntime(models: ["fast", "slow"])
+        StubLLMProtocol.enqueue("not json", model: "fast")
+        StubLLMProtocol.enqueue("still not json", model: "slow")
+        let ctx = RuntimeContext.builder()
+            .race(["fast", "slow"])
+            .build()
+
+        do {
+            _ = try await runtime.callFunction("Triage", args: [:], prompt: "Hi", outputType: Ticket.self, ctx: ctx)
+            XCTFail("Expected an output error")
+        } catch let error as SwamlError {
+            XCTAssertEqual(FailureCategory(error: error), .parse)
+        } catch {
+            XCTFail("Unexpected error: \(error)")
+        }
+    }
+
+    func testRaceRecordsWinnerSamplingSettings() async throws {
+        let registry = ClientRegistry()
+        await registry.register(name: "fast", provider: StubLLMProtocol.provider, model: "fast", retryPolicy: .none, defaultTemperature: 0.1)
+        await registry.register(name: "slow", provider: StubLLMProtocol.provider, model: "slow", retryPolicy: .none, defaultTemperature: 0.9)
+        let runtime = SwamlRuntime(clientRegistry: registry)
+        StubLLMProtocol.enqueue("not json", model: "fast")
+        StubLLMProtocol.enqueue(#"{"status": "open"}"#, model: "slow", delay: 0.2)
+        let ctx = RuntimeContext.builder()
+            .race(["slow", "fast"])
+            .build()
+
+        _ = try await runtime.callFunction("Triage", args: [:], prompt: "Hi", outputType: Ticket.self, ctx: ctx)
+
+        let settings = await runtime.samplingSettings(for: "Triage")
+        XCTAssertEqual(settings?.client, "slow")
+        XCTAssertEqual(settings?.temperature, 0.9)
+    }
+}
@@ -0,0 +1,104 @@
+import XCTest
+@testable import SWAML
+
+final class TokenCounterTests: XCTestCase {
+
+    // MARK: - Model Families
+
+    func testDetectFamilies() {
+        XCTAssertEqual(ModelFamily.detect("gpt-4o"), .openAI)
+        XCTAssertEqual(ModelFamily.detect("openai/o3-mini"), .openAI)
+        XCTAssertEqual(ModelFamily.detect("claude-sonnet-4-20250514"), .anthropic)
+        XCTAssertEqual(ModelFamily.detect("anthropic/claude-3.5-haiku"), .anthropic)
+        XCTAssertEqual(ModelFamily.detect("google/gemini-2.0-flash"), .gemini)
+        XCTAssertEqual(ModelFamily.detect("meta-llama/llama-3.1-70b-instruct"), .llama)
+        XCTAssertEqual(ModelFamily.detect("mistralai/mistral-large"), .mistral)
+        XCTAssertEqual(ModelFamily.detect("some-new-model"), .other)
+    }
+
+    // MARK: - Counting
+
+    func testCountUsesFamilyRatio() {
+        let text = String(repeating: "a", count: 400)
+
+        XCTAssertEqual(TokenCounter(family: .openAI).count(text), 100)
+        XCTAssertEqual(TokenCounter(family: .anthropic).count(text), 115)
+    }
+
+    func testCountEmptyText() {
+        XCTAssertEqual(TokenCounter(family: .openAI).count(""), 0)
+    }
+
+    func testNonASCIICountsOneTokenPerCharacter() {
+        XCTAssertEqual(TokenCounter(family: .openAI).count("日本語"), 3)
+    }
+
+    func testCountMessagesIncludesOverhead() {
+        let counter = TokenCounter(model: "gpt-4o")
+        let messages = [ChatMessage.system("abcd"), ChatMessage.user("abcdefgh")]
+
+        XCTAssertEqual(counter.count(messages), 1 + 2 + 2 * TokenCounter.messageOverhead)
+    }
+
+    // MARK: - Context Windows
+
+    func testKnownContextWindows() {
+        XCTAssertEqual(ContextWindow.tokens(for: "gpt-4o-mini"), 128_000)
+        XCTAssertEqual(ContextWindow.tokens(for: "openai/gpt-4"), 8_192)
+        XCTAssertEqual(ContextWindow.tokens(for: "gpt-4-turbo-preview"), 128_000)
+        XCTAssertEqual(ContextWindow.tokens(for: "anthropic/claude-sonnet-4"), 200_000)
+        XCTAssertNil(ContextWindow.tokens(for: "unknown-model"))
+    }
+
+    func testClientConfigContextWindowOverride() {
+        let config = ClientConfig(
+            name: "test",
+            provider: .openAI(apiKey: "key"),
+            model: "gpt-4o",
+            contextWindow: 1_000
+        )
+
+        XCTAssertEqual(config.effectiveContextWindow, 1_000)
+    }
+
+    // MARK: - Runtime
+
+    func testRuntimeCountPromptTokens() async throws {
+        let registry = ClientRegistry()
+        await registry.register(name: "default", provider: .openAI(apiKey: "key"), model: "gpt-4o")
+        let runtime = SwamlRuntime(clientRegistry: registry)
+
+        let tokens = try await runtime.countPromptTokens(String(repeating: "a", count: 40))
+
+        XCTAssertEqual(tokens, 10 + TokenCounter.messageOverhead)
+    }
+
+    func testPreflightRejectsOversizedPrompt() async {
+        let registry = ClientRegistry()
+        await registry.register(
+            name: "small",
+            provider: .openAI(apiKey: "key"),
+            model: "gpt-4o",
+            contextWindow: 50
+        )
+        let runtime = SwamlRuntime(clientRegistry: registry)
+        let ctx = RuntimeContext.builder()
+            .checkContextWindow()
+            .build()
+
+        do {
+            _ = try await runtime.callFunction(
+                "Summarize",
+                args: [:],
+                prompt: String(repeating: "word ", count: 100),
+                ctx: ctx
+            )
+            XCTFail("Expected contextWindowExceeded")
+        } catch SwamlError.contextWindowExceeded(let tokens, let limit) {
+            XCTAssertEqual(limit, 50)
+            XCTAssertGreaterThan(tokens, 50)
+        } catch {
+            XCTFail("Unexpected error: \(error)")
+        }
+    }
+}
@@ -511,6 +511,51 @@ final class TypeBuilderTests: XCTestCase {
         XCTAssertNil(schema)
     }
 
+    // MARK: - Path Resolution
+
+    func testResolveTypeName() throws {
+        let tb = TypeBuilder(enums: ["Status"])
+        tb.addClass("Resume")
+
+        XCTAssertEqual(try tb.resolveType("Resume"), .reference("Resume"))
+        XCTAssertEqual(try tb.resolveType("Status"), .reference("Status"))
+    }
+
+    func testResolvePropertyPath() throws {
+        let tb = TypeBuilder()
+        tb.addClass("Resume").addProperty("jobs", .list(.reference("Job")))
+        tb.addClass("Job").addProperty("title", .optional(.string))
+
+        XCTAssertEqual(try tb.resolveType("Resume.jobs"), .list(.reference("Job")))
+        XCTAssertEqual(try tb.resolveType("Resume.jobs.title"), .optional(.string))
+    }
+
+    func testResolveTypeErrors() {
+        let tb = TypeBuilder()
+        tb.addClass("Resume").addProperty("name", .string)
+
+        XCTAssertThrowsError(try tb.resolveType("Missing")) { error in
+            guard case TypeBuilderError.typeNotRegistered("Missing") = error else {
+                return XCTFail("Unexpected error: \(error)")
+            }
+        }
+        XCTAssertThrowsError(try tb.resolveType("Resume.email")) { error in
+            guard case TypeBuilderError.propertyNotFound(type: "Resume", property: "email") = error else {
+                return XCTFail("Unexpected error: \(error)")
+            }
+        }
+        XCTAssertThrowsError(try tb.resolveType("Resume.name.first")) { error in
+            guard case TypeBuilderError.propertyNotFound(type: "Resume.name", property: "first") = error else {
+                return XCTFail("Unexpected error: \(error)")
+            }
+        }
+        XCTAssertThrowsError(try tb.resolveType("Resume..name")) { error in
+            guard case TypeBuilderError.invalidPath = error else {
+                return XCTFail("Unexpected error: \(error)")
+            }
+        }
+    }
+
     // MARK: - TypeBuilder Serialization
 
     func testTypeBuilderSerialization() {
@@ -388,4 +388,38 @@ final class TypeCoercionTests: XCTestCase {
         XCTAssertEqual(SwamlValue.array([]).typeName, "array")
         XCTAssertEqual(SwamlValue.map([:]).typeName, "map")
     }
+
+    // MARK: - Strict Coercion
+
+    func testStrictRejectsStringToNumber() {
+        XCTAssertThrowsError(try TypeCoercion.coerce(.string("42"), to: .int, options: .strict))
+        XCTAssertThrowsError(try TypeCoercion.coerce(.string("3.5"), to: .float, options: .strict))
+    }
+
+    func testStrictRejectsNumberToString() {
+        XCTAssertThrowsError(try TypeCoercion.coerce(.int(42), to: .string, options: .strict))
+        XCTAssertThrowsError(try TypeCoercion.coerce(.bool(true), to: .string, options: .strict))
+    }
+
+    func testStrictRejectsBoolConversions() {
+        XCTAssertThrowsError(try TypeCoercion.coerce(.int(1), to: .bool, options: .strict))
+        XCTAssertThrowsError(try TypeCoercion.coerce(.string("yes"), to: .bool, options: .strict))
+        XCTAssertThrowsError(try TypeCoercion.coerce(.bool(true), to: .int, options: .strict))
+    }
+
+    func testStrictAllowsNumericWidening() throws {
+        XCTAssertEqual(try TypeCoercion.coerce(.int(3), to: .float, options: .strict), .float(3.0))
+        XCTAssertEqual(try TypeCoercion.coerce(.float(3.0), to: .int, options: .strict), .int(3))
+    }
+
+    func testStrictAppliesInsideCollections() {
+        XCTAssertThrowsError(
+            try TypeCoercion.coerce(.array([.int(1), .string("2")]), to: .list(.int), options: .strict)
+        )
+    }
+
+    func testDefaultOptionsStayLenient() throws {
+        XCTAssertEqual(try TypeCoercion.coerce(.string("42"), to: .int), .int(42))
+        XCTAssertEqual(try TypeCoercion.coerce(.int(42), to: .string), .string("42"))
+    }
 }
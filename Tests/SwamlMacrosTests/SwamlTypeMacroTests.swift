@@ -130,6 +130,25 @@ final class SwamlTypeMacroTests: XCTestCase {
                 public static var isDynamic: Bool { false }
                 public static var fieldDescriptions: [String: String] { [:] }
                 public static var fieldAliases: [String: String] { [:] }
+                public var swamlDescription: String? {
+                    switch self {
+                    case .active: return nil
+                    case .inactive: return nil
+                    }
+                }
+                public var swamlAlias: String? {
+                    switch self {
+                    case .active: return nil
+                    case .inactive: return nil
+                    }
+                }
+                public init?(swamlAlias alias: String) {
+                    switch alias {
+                    case "active": self = .active
+                    case "inactive": self = .inactive
+                    default: return nil
+                    }
+                }
             }
             """,
             macros: testMacros
@@ -158,8 +177,145 @@ final class SwamlTypeMacroTests: XCTestCase {
                 public static var isDynamic: Bool { true }
                 public static var fieldDescriptions: [String: String] { [:] }
                 public static var fieldAliases: [String: String] { [:] }
+                public var swamlDescription: String? {
+                    switch self {
+                    case .electronics: return nil
+                    case .clothing: return nil
+                    }
+                }
+                public var swamlAlias: String? {
+                    switch self {
+                    case .electronics: return nil
+                    case .clothing: return nil
+                    }
+                }
+                public init?(swamlAlias alias: String) {
+                    switch alias {
+                    case "electronics": self = .electronics
+                    case "clothing": self = .clothing
+                    default: return nil
+                    }
+                }
+            }
+            """,
+            macros: testMacros
+        )
+    }
+
+    func testEnumWithDescriptionsAndAliases() throws {
+        assertMacroExpansion(
+            """
+            @SwamlType
+            enum Priority: String {
+                @Alias("P0")
+                @Description("Drop everything")
+                case urgent
+                case normal
+            }
+            """,
+            expandedSource: """
+            enum Priority: String {
+                case urgent
+                case normal
+            }
+
+            extension Priority: SwamlTyped {
+                public static var swamlTypeName: String { "Priority" }
+                public static var swamlSchema: JSONSchema { .enum(values: ["urgent", "normal"]) }
+                public static var isDynamic: Bool { false }
+                public static var fieldDescriptions: [String: String] { ["urgent": "Drop everything"] }
+                public static var fieldAliases: [String: String] { ["urgent": "P0"] }
+                public var swamlDescription: String? {
+                    switch self {
+                    case .urgent: return "Drop everything"
+                    case .normal: return nil
+                    }
+                }
+                public var swamlAlias: String? {
+                    switch self {
+                    case .urgent: return "P0"
+                    case .normal: return nil
+                    }
+                }
+                public init?(swamlAlias alias: String) {
+                    switch alias {
+                    case "P0": self = .urgent
+                    case "normal": self = .normal
+                    default: return nil
+                    }
+                }
+            }
+            """,
+            macros: testMacros
+        )
+    }
+
+    // MARK: - Name Validation Tests
+
+    func testEmptyAliasIsDiagnosed() throws {
+        assertMacroExpansion(
+            """
+            @SwamlType
+            struct User {
+                @Alias("")
+                let name: String
+            }
+            """,
+            expandedSource: """
+            struct User {
+                let name: String
+            }
+            """,
+            diagnostics: [
+                DiagnosticSpec(message: "@Alias for 'name' is empty", line: 3, column: 5)
+            ],
+            macros: testMacros
+        )
+    }
+
+    func testCaseInsensitiveDuplicateIsDiagnosed() throws {
+        assertMacroExpansion(
+            """
+            @SwamlType
+            enum Level: String {
+                case low
+                @Alias("LOW")
+                case minimal
+            }
+            """,
+            expandedSource: """
+            enum Level: String {
+                case low
+                case minimal
+            }
+            """,
+            diagnostics: [
+                DiagnosticSpec(
+                    message: "'minimal' appears in the schema as 'LOW', which clashes with 'low' (names are compared case-insensitively)",
+                    line: 4,
+                    column: 5
+                )
+            ],
+            macros: testMacros
+        )
+    }
+
+    func testGeneratedMemberNameIsDiagnosed() throws {
+        assertMacroExpansion(
+            """
+            @SwamlType
+            enum Field: String {
+                case swamlSchema
+            }
+            """,
+            expandedSource: """
+            enum Field: String {
+                case swamlSchema
             }
             """,
+            diagnostics: [
+                DiagnosticSpec(message: "'swamlSchema' clashes with a member generated by @SwamlType; rename it", line: 3, column: 10)
+            ],
             macros: testMacros
         )
     }